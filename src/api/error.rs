@@ -10,4 +10,165 @@ pub enum ApiError {
 
     #[error("Failed to parse response: {0}")]
     Deserialization(String),
+
+    /// A mutating request was printed instead of sent because of `--dry-run`.
+    #[error("dry run: request to {0} not sent")]
+    DryRun(String),
+
+    /// No resource matches an identifier, or the server reports it missing.
+    #[error("{0}")]
+    NotFound(String),
+
+    /// An identifier matches more than one resource.
+    #[error("{0}")]
+    Ambiguous(String),
+
+    /// The API key or secret was rejected.
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// The API key is valid but lacks permission for the call.
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("request timed out: {0}")]
+    Timeout(String),
+
+    /// The TLS handshake failed, e.g. because the certificate is not trusted.
+    #[error("TLS error: {0}")]
+    Tls(String),
+}
+
+impl ApiError {
+    /// Returns true when `err` was caused by a request skipped for `--dry-run`.
+    pub fn is_dry_run(err: &anyhow::Error) -> bool {
+        err.chain()
+            .any(|cause| matches!(cause.downcast_ref(), Some(ApiError::DryRun(_))))
+    }
+
+    /// The first `ApiError` in the cause chain of `err`, if any.
+    pub fn find(err: &anyhow::Error) -> Option<&ApiError> {
+        err.chain().find_map(|cause| cause.downcast_ref())
+    }
+
+    /// Classify an error reported by the server, either as an `error_message`
+    /// in the response body or as a bare HTTP status.
+    ///
+    /// Kasm reports most failures as HTTP 200 with an `error_message`, so the
+    /// message text is checked as well as the status. Server errors (5xx) and
+    /// unrecognised messages stay [`ApiError::Server`].
+    pub fn from_response(status: u16, message: String) -> Self {
+        let lower = message.to_lowercase();
+        let has = |needles: &[&str]| needles.iter().any(|n| lower.contains(n));
+        match status {
+            401 => ApiError::Unauthorized(message),
+            403 => ApiError::Forbidden(message),
+            404 => ApiError::NotFound(message),
+            500.. => ApiError::Server { status, message },
+            _ if has(&["forbidden", "permission", "not permitted", "not allowed"]) => {
+                ApiError::Forbidden(message)
+            }
+            _ if has(&[
+                "unauthorized",
+                "invalid api key",
+                "invalid credentials",
+                "authentication failed",
+            ]) =>
+            {
+                ApiError::Unauthorized(message)
+            }
+            _ if has(&["not found", "does not exist", "no such"]) => ApiError::NotFound(message),
+            _ => ApiError::Server { status, message },
+        }
+    }
+
+    /// Classify a failure to send a request or read its response.
+    pub fn from_transport(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Timeout(_) => ApiError::Timeout(err.to_string()),
+            ureq::Error::Tls(_) | ureq::Error::Pem(_) | ureq::Error::Rustls(_) => {
+                ApiError::Tls(err.to_string())
+            }
+            // rustls reports handshake failures through the socket's io::Error.
+            ureq::Error::Io(ref io)
+                if io
+                    .get_ref()
+                    .is_some_and(|inner| inner.is::<rustls::Error>()) =>
+            {
+                ApiError::Tls(io.get_ref().map(|e| e.to_string()).unwrap_or_default())
+            }
+            ureq::Error::Io(ref io) if io.kind() == std::io::ErrorKind::TimedOut => {
+                ApiError::Timeout(err.to_string())
+            }
+            _ => ApiError::Connection(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(status: u16, message: &str) -> &'static str {
+        match ApiError::from_response(status, message.to_string()) {
+            ApiError::NotFound(_) => "not_found",
+            ApiError::Unauthorized(_) => "unauthorized",
+            ApiError::Forbidden(_) => "forbidden",
+            ApiError::Server { .. } => "server",
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn classifies_http_status() {
+        assert_eq!(kind(401, "HTTP 401"), "unauthorized");
+        assert_eq!(kind(403, "HTTP 403"), "forbidden");
+        assert_eq!(kind(404, "HTTP 404"), "not_found");
+        assert_eq!(kind(500, "HTTP 500"), "server");
+        assert_eq!(kind(502, "User not found"), "server");
+    }
+
+    #[test]
+    fn classifies_error_messages() {
+        assert_eq!(kind(200, "Unauthorized"), "unauthorized");
+        assert_eq!(kind(200, "Invalid API Key"), "unauthorized");
+        assert_eq!(
+            kind(200, "Insufficient permission for this action"),
+            "forbidden"
+        );
+        assert_eq!(kind(200, "Kasm not found"), "not_found");
+        assert_eq!(kind(200, "User does not exist"), "not_found");
+        assert_eq!(kind(200, "Image already exists"), "server");
+    }
+
+    #[test]
+    fn classifies_transport_errors() {
+        let err = ApiError::from_transport(ureq::Error::Tls("bad certificate"));
+        assert!(matches!(err, ApiError::Tls(_)), "{err:?}");
+
+        let rustls_io = std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            rustls::Error::InvalidCertificate(rustls::CertificateError::UnknownIssuer),
+        );
+        let err = ApiError::from_transport(ureq::Error::Io(rustls_io));
+        assert!(matches!(err, ApiError::Tls(_)), "{err:?}");
+        assert!(err.to_string().contains("UnknownIssuer"), "{err}");
+
+        let err = ApiError::from_transport(ureq::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "timed out",
+        )));
+        assert!(matches!(err, ApiError::Timeout(_)), "{err:?}");
+
+        let err = ApiError::from_transport(ureq::Error::ConnectionFailed);
+        assert!(matches!(err, ApiError::Connection(_)), "{err:?}");
+    }
+
+    #[test]
+    fn finds_api_errors_behind_context() {
+        let err = anyhow::Error::from(ApiError::NotFound("image \"x\" not found".into()))
+            .context("failed to get image");
+        assert!(matches!(ApiError::find(&err), Some(ApiError::NotFound(_))));
+        assert!(ApiError::find(&anyhow::anyhow!("plain")).is_none());
+    }
 }
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::models::group::Group;
+use crate::models::user::User;
+
+use super::{KasmClient, resolve_identifier};
+
+/// Parameters for creating a new group.
+#[derive(Serialize)]
+pub struct CreateGroupParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+}
+
+/// Request body for updating a group.
+/// Only `group_id` is required; all other fields are optional
+/// and only sent when set (via `#[serde(skip_serializing_if)]`).
+#[derive(Serialize)]
+pub struct UpdateGroupRequest {
+    pub group_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct TargetGroup<'a> {
+    group_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct TargetUser<'a> {
+    user_id: &'a str,
+}
+
+impl KasmClient {
+    pub fn get_groups(&self) -> Result<Vec<Group>> {
+        #[derive(Serialize)]
+        struct Req {}
+
+        #[derive(Deserialize)]
+        struct Resp {
+            groups: Vec<Group>,
+        }
+
+        let resp: Resp = self.post("admin/get_groups", &Req {})?;
+        Ok(resp.groups)
+    }
+
+    pub fn create_group(&self, params: &CreateGroupParams) -> Result<Group> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_group: &'a CreateGroupParams,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            group: Group,
+        }
+
+        let resp: Resp = self.post(
+            "admin/create_group",
+            &Req {
+                target_group: params,
+            },
+        )?;
+        Ok(resp.group)
+    }
+
+    pub fn update_group(&self, req: &UpdateGroupRequest) -> Result<Group> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_group: &'a UpdateGroupRequest,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            group: Group,
+        }
+
+        let resp: Resp = self.post("admin/update_group", &Req { target_group: req })?;
+        Ok(resp.group)
+    }
+
+    pub fn delete_group(&self, group_id: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_group: TargetGroup<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {}
+
+        let _: Resp = self.post(
+            "admin/delete_group",
+            &Req {
+                target_group: TargetGroup { group_id },
+            },
+        )?;
+        Ok(())
+    }
+
+    /// List the users that are members of a group.
+    pub fn get_group_users(&self, group_id: &str) -> Result<Vec<User>> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_group: TargetGroup<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            users: Vec<User>,
+        }
+
+        let resp: Resp = self.post(
+            "admin/get_users_group",
+            &Req {
+                target_group: TargetGroup { group_id },
+            },
+        )?;
+        Ok(resp.users)
+    }
+
+    pub fn add_user_to_group(&self, user_id: &str, group_id: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_user: TargetUser<'a>,
+            target_group: TargetGroup<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {}
+
+        let _: Resp = self.post(
+            "public/add_user_group",
+            &Req {
+                target_user: TargetUser { user_id },
+                target_group: TargetGroup { group_id },
+            },
+        )?;
+        Ok(())
+    }
+
+    pub fn remove_user_from_group(&self, user_id: &str, group_id: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_user: TargetUser<'a>,
+            target_group: TargetGroup<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {}
+
+        let _: Resp = self.post(
+            "public/remove_user_group",
+            &Req {
+                target_user: TargetUser { user_id },
+                target_group: TargetGroup { group_id },
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Resolve an identifier to a [`Group`].
+    ///
+    /// Matches an exact `group_id`, then a `group_id` prefix, then a
+    /// case-insensitive group `name`, in the same way as [`KasmClient::resolve_image`].
+    pub fn resolve_group(&self, identifier: &str) -> Result<Group> {
+        let groups = self.get_groups()?;
+        resolve_identifier(
+            groups,
+            identifier,
+            "group",
+            |g| &g.group_id,
+            |g| g.name.as_deref(),
+        )
+    }
+}
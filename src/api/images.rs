@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::image::Image;
 
-use super::KasmClient;
+use super::{KasmClient, resolve_identifier};
 
 /// Parameters for creating a new workspace image.
 #[derive(Serialize)]
@@ -117,66 +117,13 @@ impl KasmClient {
     /// Returns a descriptive error when no match is found or the match is ambiguous.
     pub fn resolve_image(&self, identifier: &str) -> Result<Image> {
         let images = self.get_images()?;
-
-        // 1. Exact image_id match
-        if let Some(img) = images.iter().find(|img| img.image_id == identifier) {
-            return Ok(img.clone());
-        }
-
-        // 2. image_id prefix match
-        let prefix_matches: Vec<&Image> = images
-            .iter()
-            .filter(|img| img.image_id.starts_with(identifier))
-            .collect();
-
-        match prefix_matches.len() {
-            1 => return Ok(prefix_matches[0].clone()),
-            n if n > 1 => {
-                let ids: Vec<&str> = prefix_matches
-                    .iter()
-                    .map(|img| img.image_id.as_str())
-                    .collect();
-                anyhow::bail!(
-                    "ambiguous image prefix {:?}: matches {} images ({})",
-                    identifier,
-                    n,
-                    ids.join(", ")
-                );
-            }
-            _ => {}
-        }
-
-        // 3. Case-insensitive friendly_name match
-        let ident_lower = identifier.to_lowercase();
-        let name_matches: Vec<&Image> = images
-            .iter()
-            .filter(|img| {
-                img.friendly_name
-                    .as_deref()
-                    .map(|n| n.to_lowercase() == ident_lower)
-                    .unwrap_or(false)
-            })
-            .collect();
-
-        match name_matches.len() {
-            1 => Ok(name_matches[0].clone()),
-            n if n > 1 => {
-                let ids: Vec<&str> = name_matches
-                    .iter()
-                    .map(|img| img.image_id.as_str())
-                    .collect();
-                anyhow::bail!(
-                    "ambiguous image name {:?}: matches {} images ({})",
-                    identifier,
-                    n,
-                    ids.join(", ")
-                );
-            }
-            _ => anyhow::bail!(
-                "image {:?} not found (tried exact ID, ID prefix, and name match)",
-                identifier
-            ),
-        }
+        resolve_identifier(
+            images,
+            identifier,
+            "image",
+            |img| &img.image_id,
+            |img| img.friendly_name.as_deref(),
+        )
     }
 
     pub fn delete_image(&self, image_id: &str) -> Result<()> {
@@ -1,21 +1,28 @@
 pub mod agents;
 pub mod error;
+pub mod groups;
 pub mod images;
 pub mod reports;
+mod retry;
 pub mod servers;
 pub mod sessions;
+pub mod tls;
+mod trace;
+pub mod users;
 pub mod zones;
 
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use ureq::Agent;
 use ureq::tls::TlsConfig;
 
-use crate::config::model::Context;
+use crate::config::model::{Context, RetryConfig};
 
 use self::error::ApiError;
+use self::retry::CallKind;
 
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
@@ -24,6 +31,9 @@ pub struct KasmClient {
     base_url: String,
     api_key: String,
     api_secret: String,
+    dry_run: bool,
+    retry: RetryConfig,
+    verbosity: u8,
 }
 
 #[derive(Deserialize)]
@@ -39,10 +49,10 @@ impl KasmClient {
             .timeout_global(Some(timeout))
             .http_status_as_error(false);
 
-        if context.insecure_skip_tls_verify {
-            config_builder =
-                config_builder.tls_config(TlsConfig::builder().disable_verification(true).build());
-        }
+        let tls_builder =
+            TlsConfig::builder().disable_verification(context.insecure_skip_tls_verify);
+        let tls_config = tls::configure(tls_builder, context)?.build();
+        config_builder = config_builder.tls_config(tls_config);
 
         let agent: Agent = config_builder.build().into();
 
@@ -61,14 +71,45 @@ impl KasmClient {
             base_url: context.server.trim_end_matches('/').to_string(),
             api_key: context.api_key.clone(),
             api_secret: context.api_secret.clone(),
+            dry_run: false,
+            retry: context.retry.clone().unwrap_or_default(),
+            verbosity: 0,
         })
     }
 
+    /// Print mutating requests instead of sending them. Read-only requests
+    /// still go to the server so that targets resolve exactly as they would.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Trace requests to stderr: `1` logs each request, its status, latency,
+    /// and any retries; `2` adds the redacted request and response bodies.
+    pub fn with_verbosity(mut self, verbosity: u8) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
     /// Core POST helper — `path` is appended to `/api/` (e.g. `"public/get_kasms"` or `"stop_kasm"`).
     fn post<Req, Resp>(&self, path: &str, body: &Req) -> Result<Resp>
     where
         Req: Serialize,
         Resp: for<'de> Deserialize<'de>,
+    {
+        let body = self.post_raw(path, body)?;
+        serde_json::from_slice(&body).map_err(|e| ApiError::Deserialization(format!("{e}")).into())
+    }
+
+    /// Like [`KasmClient::post`], but return the response body undecoded,
+    /// for endpoints that answer with binary data such as images.
+    fn post_raw<Req>(&self, path: &str, body: &Req) -> Result<Vec<u8>>
+    where
+        Req: Serialize,
     {
         let mut payload = serde_json::to_value(body)?;
         let obj = payload
@@ -78,44 +119,182 @@ impl KasmClient {
         obj.insert("api_key_secret".into(), self.api_secret.clone().into());
 
         let url = format!("{}/api/{}", self.base_url, path);
-        let response = self
-            .agent
-            .post(&url)
-            .send_json(&payload)
-            .map_err(|e| ApiError::Connection(e.to_string()))?;
+        if self.dry_run && is_mutating(path) {
+            println!("{}", dry_run_request(&url, &payload)?);
+            return Err(ApiError::DryRun(path.to_string()).into());
+        }
+
+        let retries = retry::retries_for(&self.retry, CallKind::of(path));
+        let mut attempt = 0;
+        loop {
+            match self.send(path, &url, &payload) {
+                Err(e) if attempt < retries && retry::is_transient(&e) => {
+                    attempt += 1;
+                    let delay = retry::backoff(&self.retry, attempt);
+                    if self.verbosity > 0 {
+                        eprintln!("retrying {path} in {delay:?} (retry {attempt}/{retries}): {e}");
+                    }
+                    thread::sleep(delay);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Send one request and return the response body once it is known to
+    /// be a success.
+    fn send(&self, path: &str, url: &str, payload: &serde_json::Value) -> Result<Vec<u8>> {
+        if self.verbosity > 0 {
+            eprintln!("> POST {url}");
+        }
+        if self.verbosity > 1 {
+            eprintln!("{}", trace::request_body(payload));
+        }
+        let started = Instant::now();
+
+        let response = self.agent.post(url).send_json(payload).map_err(|e| {
+            self.trace_failure(path, started, &e);
+            ApiError::from_transport(e)
+        })?;
 
         let status = response.status().as_u16();
 
         const MAX_BODY_SIZE: u64 = 128 * 1024 * 1024; // 128 MB
         let mut body = response.into_body();
-        let body_text = body
+        let body = body
             .with_config()
             .limit(MAX_BODY_SIZE)
-            .read_to_string()
-            .map_err(|e| ApiError::Connection(e.to_string()))?;
+            .read_to_vec()
+            .map_err(|e| {
+                self.trace_failure(path, started, &e);
+                ApiError::from_transport(e)
+            })?;
+
+        if self.verbosity > 0 {
+            eprintln!("< {status} {path} in {:?}", started.elapsed());
+        }
+        if self.verbosity > 1 {
+            eprintln!("{}", trace::response_body(&body));
+        }
 
         // Check for API-level error in response body
-        if let Ok(err_resp) = serde_json::from_str::<ErrorResponse>(&body_text)
+        if let Ok(err_resp) = serde_json::from_slice::<ErrorResponse>(&body)
             && let Some(msg) = err_resp.error_message
         {
-            return Err(ApiError::Server {
-                status,
-                message: msg,
-            }
-            .into());
+            return Err(ApiError::from_response(status, msg).into());
         }
 
         // Check HTTP status
         if !(200..300).contains(&status) {
-            return Err(ApiError::Server {
-                status,
-                message: format!("HTTP {status}"),
-            }
+            return Err(ApiError::from_response(status, format!("HTTP {status}")).into());
+        }
+
+        Ok(body)
+    }
+
+    fn trace_failure(&self, path: &str, started: Instant, err: &ureq::Error) {
+        if self.verbosity > 0 {
+            eprintln!("< {path} failed after {:?}: {err}", started.elapsed());
+        }
+    }
+}
+
+/// Every read-only endpoint is named `get_*`; anything else changes state.
+fn is_mutating(path: &str) -> bool {
+    CallKind::of(path) != CallKind::ReadOnly
+}
+
+/// Describe a request for `--dry-run`, redacted the same way as a trace.
+fn dry_run_request(url: &str, payload: &serde_json::Value) -> Result<String> {
+    let mut payload = payload.clone();
+    trace::redact(&mut payload, &trace::SENSITIVE_KEYS);
+    Ok(format!(
+        "POST {url}\n{}",
+        serde_json::to_string_pretty(&payload)?
+    ))
+}
+
+/// Resolve an identifier against a list of resources.
+///
+/// Match priority:
+/// 1. Exact ID match
+/// 2. ID prefix match (error if ambiguous)
+/// 3. Case-insensitive name match (error if ambiguous)
+///
+/// `kind` is used in error messages (e.g. `"image"`, `"user"`).
+pub(crate) fn resolve_identifier<T>(
+    items: Vec<T>,
+    identifier: &str,
+    kind: &str,
+    id: impl Fn(&T) -> &str,
+    name: impl Fn(&T) -> Option<&str>,
+) -> Result<T> {
+    // 1. Exact ID match
+    if let Some(pos) = items.iter().position(|item| id(item) == identifier) {
+        return Ok(items.into_iter().nth(pos).expect("position is in bounds"));
+    }
+
+    // 2. ID prefix match
+    let prefix_matches: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| id(item).starts_with(identifier))
+        .map(|(i, _)| i)
+        .collect();
+
+    match prefix_matches.len() {
+        1 => {
+            return Ok(items
+                .into_iter()
+                .nth(prefix_matches[0])
+                .expect("position is in bounds"));
+        }
+        n if n > 1 => {
+            let ids: Vec<&str> = prefix_matches.iter().map(|&i| id(&items[i])).collect();
+            return Err(ApiError::Ambiguous(format!(
+                "ambiguous {kind} prefix {:?}: matches {} {kind}s ({})",
+                identifier,
+                n,
+                ids.join(", ")
+            ))
             .into());
         }
+        _ => {}
+    }
 
-        serde_json::from_str(&body_text)
-            .map_err(|e| ApiError::Deserialization(format!("{e}")).into())
+    // 3. Case-insensitive name match
+    let ident_lower = identifier.to_lowercase();
+    let name_matches: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            name(item)
+                .map(|n| n.to_lowercase() == ident_lower)
+                .unwrap_or(false)
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    match name_matches.len() {
+        1 => Ok(items
+            .into_iter()
+            .nth(name_matches[0])
+            .expect("position is in bounds")),
+        n if n > 1 => {
+            let ids: Vec<&str> = name_matches.iter().map(|&i| id(&items[i])).collect();
+            Err(ApiError::Ambiguous(format!(
+                "ambiguous {kind} name {:?}: matches {} {kind}s ({})",
+                identifier,
+                n,
+                ids.join(", ")
+            ))
+            .into())
+        }
+        _ => Err(ApiError::NotFound(format!(
+            "{kind} {:?} not found (tried exact ID, ID prefix, and name match)",
+            identifier
+        ))
+        .into()),
     }
 }
 
@@ -131,6 +310,10 @@ mod tests {
             api_secret: "test-secret".into(),
             insecure_skip_tls_verify: false,
             timeout_seconds: None,
+            retry: None,
+            certificate_authority: None,
+            client_certificate: None,
+            client_key: None,
         }
     }
 
@@ -244,4 +427,80 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    fn dry_run_prints_mutating_requests_without_sending() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/api/stop_kasm").expect(0).create();
+
+        let ctx = test_context(&server.url());
+        let client = KasmClient::new(&ctx).unwrap().with_dry_run(true);
+        let result: Result<DummyResponse> = client.post(
+            "stop_kasm",
+            &DummyRequest {
+                target_user: "u1".into(),
+            },
+        );
+
+        let err = result.unwrap_err();
+        assert!(ApiError::is_dry_run(&err), "error was: {err}");
+        mock.assert();
+    }
+
+    #[test]
+    fn dry_run_still_sends_read_only_requests() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/api/admin/get_users")
+            .with_status(200)
+            .with_body(r#"{"ok":true}"#)
+            .create();
+
+        let ctx = test_context(&server.url());
+        let client = KasmClient::new(&ctx).unwrap().with_dry_run(true);
+        let resp: DummyResponse = client
+            .post(
+                "admin/get_users",
+                &DummyRequest {
+                    target_user: "u1".into(),
+                },
+            )
+            .unwrap();
+
+        assert!(resp.ok);
+        mock.assert();
+    }
+
+    #[test]
+    fn dry_run_request_redacts_credentials() {
+        let payload = serde_json::json!({
+            "kasm_id": "k1",
+            "api_key": "test-key",
+            "api_key_secret": "test-secret",
+        });
+        let text = dry_run_request("https://kasm.example.com/api/stop_kasm", &payload).unwrap();
+        assert!(text.starts_with("POST https://kasm.example.com/api/stop_kasm\n"));
+        assert!(text.contains(r#""kasm_id": "k1""#));
+        assert!(text.contains(r#""api_key": "<redacted>""#));
+        assert!(text.contains(r#""api_key_secret": "<redacted>""#));
+        assert!(!text.contains("test-secret"));
+
+        let payload = serde_json::json!({
+            "api_key": "test-key",
+            "target_user": {"user_id": "u1", "password": "hunter2"},
+        });
+        let text =
+            dry_run_request("https://kasm.example.com/api/admin/update_user", &payload).unwrap();
+        assert!(text.contains(r#""password": "<redacted>""#));
+        assert!(!text.contains("hunter2"));
+    }
+
+    #[test]
+    fn only_get_endpoints_are_read_only() {
+        assert!(!is_mutating("public/get_kasms"));
+        assert!(!is_mutating("admin/get_agent_report"));
+        assert!(is_mutating("stop_kasm"));
+        assert!(is_mutating("public/request_kasm"));
+        assert!(is_mutating("admin/update_image"));
+    }
 }
@@ -0,0 +1,165 @@
+//! Retrying transient failures with exponential backoff.
+//!
+//! Whether a call may be retried depends on what it does: reads are always
+//! safe to repeat, idempotent changes (stop, pause, update, delete, ...) end in
+//! the same state however often they are sent, and calls that create
+//! something or run a command could do it twice.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::config::model::RetryConfig;
+
+use super::error::ApiError;
+
+/// How safe it is to repeat a call, derived from its endpoint name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CallKind {
+    ReadOnly,
+    Idempotent,
+    NonIdempotent,
+}
+
+impl CallKind {
+    /// Classify an endpoint path such as `public/get_kasms` or `stop_kasm`.
+    pub(crate) fn of(path: &str) -> Self {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        if name.starts_with("get_") {
+            CallKind::ReadOnly
+        } else if ["create_", "request_", "exec_"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            CallKind::NonIdempotent
+        } else {
+            CallKind::Idempotent
+        }
+    }
+}
+
+/// Number of retries `policy` allows for a call of `kind`.
+pub(crate) fn retries_for(policy: &RetryConfig, kind: CallKind) -> u32 {
+    match kind {
+        CallKind::ReadOnly => policy.max_retries,
+        CallKind::Idempotent if policy.retry_mutating => policy.max_retries,
+        _ => 0,
+    }
+}
+
+/// Connection failures, timeouts, rate limiting, and server errors may
+/// succeed on retry.
+pub(crate) fn is_transient(err: &anyhow::Error) -> bool {
+    match err.downcast_ref::<ApiError>() {
+        Some(ApiError::Connection(_) | ApiError::Timeout(_)) => true,
+        Some(ApiError::Server { status, .. }) => *status == 429 || *status >= 500,
+        _ => false,
+    }
+}
+
+/// Delay before retry number `retry` (starting at 1): the base delay doubled
+/// for each earlier retry, capped at the maximum, and with jitter a random
+/// value between half and all of that.
+pub(crate) fn backoff(policy: &RetryConfig, retry: u32) -> Duration {
+    let exponent = retry.saturating_sub(1).min(32);
+    let delay = policy
+        .base_delay_ms
+        .saturating_mul(1u64 << exponent)
+        .min(policy.max_delay_ms);
+    let delay = if policy.jitter && delay > 1 {
+        let half = delay / 2;
+        half + random() % (delay - half + 1)
+    } else {
+        delay
+    };
+    Duration::from_millis(delay)
+}
+
+/// A random number from the standard library's per-process hash seed, which is
+/// plenty for spreading out retries.
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(jitter: bool) -> RetryConfig {
+        RetryConfig {
+            max_retries: 4,
+            base_delay_ms: 100,
+            max_delay_ms: 500,
+            jitter,
+            retry_mutating: false,
+        }
+    }
+
+    #[test]
+    fn classifies_calls_by_endpoint_name() {
+        assert_eq!(CallKind::of("public/get_kasms"), CallKind::ReadOnly);
+        assert_eq!(CallKind::of("admin/get_agent_report"), CallKind::ReadOnly);
+        assert_eq!(CallKind::of("stop_kasm"), CallKind::Idempotent);
+        assert_eq!(CallKind::of("admin/update_image"), CallKind::Idempotent);
+        assert_eq!(CallKind::of("public/destroy_kasm"), CallKind::Idempotent);
+        assert_eq!(CallKind::of("public/request_kasm"), CallKind::NonIdempotent);
+        assert_eq!(CallKind::of("admin/create_user"), CallKind::NonIdempotent);
+        assert_eq!(
+            CallKind::of("public/exec_command_kasm"),
+            CallKind::NonIdempotent
+        );
+    }
+
+    #[test]
+    fn mutating_calls_are_retried_only_when_opted_in() {
+        let mut p = policy(false);
+        assert_eq!(retries_for(&p, CallKind::ReadOnly), 4);
+        assert_eq!(retries_for(&p, CallKind::Idempotent), 0);
+        assert_eq!(retries_for(&p, CallKind::NonIdempotent), 0);
+        p.retry_mutating = true;
+        assert_eq!(retries_for(&p, CallKind::Idempotent), 4);
+        assert_eq!(retries_for(&p, CallKind::NonIdempotent), 0);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let p = policy(false);
+        let delays: Vec<u64> = (1..=5)
+            .map(|retry| backoff(&p, retry).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500]);
+        assert_eq!(backoff(&p, 200), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jitter_stays_between_half_and_full_delay() {
+        let p = policy(true);
+        for _ in 0..100 {
+            let delay = backoff(&p, 2).as_millis();
+            assert!((100..=200).contains(&delay), "delay was {delay}ms");
+        }
+    }
+
+    #[test]
+    fn only_connection_timeout_and_server_errors_are_transient() {
+        let err = |e: ApiError| anyhow::Error::from(e);
+        assert!(is_transient(&err(ApiError::Connection("reset".into()))));
+        assert!(is_transient(&err(ApiError::Timeout(
+            "timeout: global".into()
+        ))));
+        assert!(!is_transient(&err(ApiError::Tls("UnknownIssuer".into()))));
+        assert!(is_transient(&err(ApiError::Server {
+            status: 503,
+            message: "HTTP 503".into(),
+        })));
+        assert!(is_transient(&err(ApiError::Server {
+            status: 429,
+            message: "HTTP 429".into(),
+        })));
+        assert!(!is_transient(&err(ApiError::Unauthorized(
+            "Unauthorized".into()
+        ))));
+        assert!(!is_transient(&err(ApiError::Deserialization("eof".into()))));
+        assert!(!is_transient(&anyhow::anyhow!("other")));
+    }
+}
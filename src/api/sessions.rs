@@ -1,9 +1,13 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
-use crate::models::session::{CreateSessionResponse, Session};
+use crate::models::session::{
+    CreateSessionResponse, JoinSessionResponse, Screenshot, Session, SessionViewer,
+};
+use crate::models::stats::{BottleneckStats, FrameStats};
 
 use super::KasmClient;
+use super::error::ApiError;
 
 impl KasmClient {
     pub fn get_kasms(&self) -> Result<Vec<Session>> {
@@ -106,13 +110,176 @@ impl KasmClient {
         Ok(())
     }
 
+    /// Reset a session's keepalive timer, which extends its expiration.
+    ///
+    /// Fails when the user's usage limit is reached, since the session can
+    /// then no longer be extended.
+    pub fn keepalive(&self, kasm_id: &str, user_id: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            usage_reached: bool,
+        }
+
+        let resp: Resp = self.post("public/keepalive", &Req { kasm_id, user_id })?;
+        if resp.usage_reached {
+            anyhow::bail!("usage limit reached; session {kasm_id:?} cannot be extended");
+        }
+        Ok(())
+    }
+
+    /// Enable sharing for a session and return its share ID.
+    pub fn create_kasm_share_id(&self, kasm_id: &str, user_id: &str) -> Result<String> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            share_id: String,
+        }
+
+        let resp: Resp = self.post("public/create_kasm_share_id", &Req { kasm_id, user_id })?;
+        Ok(resp.share_id)
+    }
+
+    /// Disable sharing for a session. Viewers that already joined are disconnected.
+    pub fn delete_kasm_share_id(&self, kasm_id: &str, user_id: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {}
+
+        let _: Resp = self.post("public/delete_kasm_share_id", &Req { kasm_id, user_id })?;
+        Ok(())
+    }
+
+    /// List the users currently viewing a shared session.
+    pub fn get_kasm_viewers(&self, kasm_id: &str, user_id: &str) -> Result<Vec<SessionViewer>> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            #[serde(default)]
+            viewers: Vec<SessionViewer>,
+        }
+
+        let resp: Resp = self.post("public/get_kasm_viewers", &Req { kasm_id, user_id })?;
+        Ok(resp.viewers)
+    }
+
+    /// Join a shared session as a viewer.
+    pub fn join_kasm(&self, share_id: &str) -> Result<JoinSessionResponse> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            share_id: &'a str,
+        }
+
+        self.post("public/join_kasm", &Req { share_id })
+    }
+
+    /// The link viewers open in a browser to join a shared session.
+    pub fn share_url(&self, share_id: &str) -> String {
+        format!("{}/#/join/{share_id}", self.base_url)
+    }
+
+    /// Capture a screenshot of a running session, scaled to `width` pixels
+    /// when given.
+    pub fn get_kasm_screenshot(
+        &self,
+        kasm_id: &str,
+        user_id: &str,
+        width: Option<u32>,
+    ) -> Result<Screenshot> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            width: Option<u32>,
+        }
+
+        let data = self.post_raw(
+            "public/get_kasm_screenshot",
+            &Req {
+                kasm_id,
+                user_id,
+                width,
+            },
+        )?;
+        if data.is_empty() {
+            return Err(ApiError::Deserialization("empty screenshot".into()).into());
+        }
+        Ok(Screenshot { data })
+    }
+
+    /// Frame timings of a session, including those reported by every
+    /// connected client.
+    pub fn get_kasm_frame_stats(&self, kasm_id: &str, user_id: &str) -> Result<FrameStats> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+            client: &'a str,
+        }
+
+        self.post(
+            "public/get_kasm_frame_stats",
+            &Req {
+                kasm_id,
+                user_id,
+                client: "all",
+            },
+        )
+    }
+
+    /// How often a session was held back by the agent's CPU or the network.
+    pub fn get_kasm_bottleneck_stats(
+        &self,
+        kasm_id: &str,
+        user_id: &str,
+    ) -> Result<BottleneckStats> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            kasm_id: &'a str,
+            user_id: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            bottleneck_stats: BottleneckStats,
+        }
+
+        let resp: Resp = self.post(
+            "public/get_kasm_bottleneck_stats",
+            &Req { kasm_id, user_id },
+        )?;
+        Ok(resp.bottleneck_stats)
+    }
+
     /// Look up the user_id for a session by scanning all active sessions.
     pub fn resolve_user_id(&self, kasm_id: &str) -> Result<String> {
         let sessions = self.get_kasms()?;
         let session = sessions
             .iter()
             .find(|s| s.kasm_id == kasm_id)
-            .ok_or_else(|| anyhow::anyhow!("session {kasm_id:?} not found"))?;
+            .ok_or_else(|| ApiError::NotFound(format!("session {kasm_id:?} not found")))?;
         session
             .user_id
             .clone()
@@ -0,0 +1,145 @@
+//! Request tracing for `-v`/`--verbose`.
+//!
+//! `-v` logs one line per request and response (path, HTTP status, latency);
+//! `-vv` adds the request body and the start of the response body. Bodies are
+//! logged with credentials, user passwords, session tokens, and server
+//! connection details masked so that a trace can be attached to a support
+//! ticket as is.
+
+use serde_json::Value;
+
+/// Keys whose values never appear in a trace, wherever they are nested.
+pub(crate) const SENSITIVE_KEYS: [&str; 5] = [
+    "api_key",
+    "api_key_secret",
+    "password",
+    "session_token",
+    "connection_info",
+];
+
+/// Response bodies longer than this many characters are cut off.
+const MAX_BODY_CHARS: usize = 2048;
+
+const REDACTED: &str = "<redacted>";
+
+/// Replace the value of every non-null `keys` entry in `value`, at any depth.
+pub(crate) fn redact(value: &mut Value, keys: &[&str]) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if keys.contains(&key.as_str()) {
+                    if !field.is_null() {
+                        *field = REDACTED.into();
+                    }
+                } else {
+                    redact(field, keys);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| redact(item, keys)),
+        _ => {}
+    }
+}
+
+/// The request body as pretty JSON, redacted.
+pub(crate) fn request_body(payload: &Value) -> String {
+    let mut payload = payload.clone();
+    redact(&mut payload, &SENSITIVE_KEYS);
+    serde_json::to_string_pretty(&payload).unwrap_or_default()
+}
+
+/// The response body, redacted when it is JSON and truncated when long.
+/// Binary bodies, such as screenshots, are only described by their size.
+pub(crate) fn response_body(body: &[u8]) -> String {
+    let Ok(text) = std::str::from_utf8(body) else {
+        return format!("<{} bytes of binary data>", body.len());
+    };
+    let text = match serde_json::from_str::<Value>(text) {
+        Ok(mut value) => {
+            redact(&mut value, &SENSITIVE_KEYS);
+            value.to_string()
+        }
+        Err(_) => text.to_string(),
+    };
+    truncate(&text, MAX_BODY_CHARS)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}... ({} more bytes)", &text[..end], text.len() - end),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn redacts_sensitive_keys_at_any_depth() {
+        let mut value = json!({
+            "api_key": "key",
+            "api_key_secret": "secret",
+            "target_server": {"hostname": "h1", "connection_info": "{\"pw\":1}"},
+            "kasms": [{"kasm_id": "k1", "session_token": "tok"}],
+            "user_id": "u1",
+        });
+        redact(&mut value, &SENSITIVE_KEYS);
+        assert_eq!(
+            value,
+            json!({
+                "api_key": "<redacted>",
+                "api_key_secret": "<redacted>",
+                "target_server": {"hostname": "h1", "connection_info": "<redacted>"},
+                "kasms": [{"kasm_id": "k1", "session_token": "<redacted>"}],
+                "user_id": "u1",
+            })
+        );
+    }
+
+    #[test]
+    fn request_body_redacts_user_passwords() {
+        let params = crate::api::users::CreateUserParams {
+            username: "alice".into(),
+            password: "hunter2".into(),
+            first_name: None,
+            last_name: None,
+            organization: None,
+            phone: None,
+            locked: false,
+            disabled: false,
+        };
+        let body = request_body(&json!({"api_key": "key", "target_user": params}));
+        assert!(
+            body.contains(r#""password": "<redacted>""#),
+            "body was: {body}"
+        );
+        assert!(body.contains(r#""username": "alice""#));
+        assert!(!body.contains("hunter2"));
+    }
+
+    #[test]
+    fn null_values_stay_null() {
+        let mut value = json!({"connection_info": null});
+        redact(&mut value, &SENSITIVE_KEYS);
+        assert_eq!(value, json!({"connection_info": null}));
+    }
+
+    #[test]
+    fn response_body_is_redacted_and_truncated() {
+        let body = response_body(br#"{"session_token":"tok","kasm_id":"k1"}"#);
+        assert_eq!(body, r#"{"session_token":"<redacted>","kasm_id":"k1"}"#);
+
+        let long = "é".repeat(MAX_BODY_CHARS + 10);
+        let body = response_body(long.as_bytes());
+        assert!(body.starts_with(&"é".repeat(MAX_BODY_CHARS)));
+        assert!(body.ends_with("... (20 more bytes)"), "body was: {body}");
+    }
+
+    #[test]
+    fn binary_response_body_is_summarised() {
+        let body = response_body(&[0x89, b'P', b'N', b'G', 0xff, 0x00]);
+        assert_eq!(body, "<6 bytes of binary data>");
+    }
+}
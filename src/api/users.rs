@@ -0,0 +1,163 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::models::user::User;
+
+use super::{KasmClient, resolve_identifier};
+
+/// Parameters for creating a new user.
+#[derive(Serialize)]
+pub struct CreateUserParams {
+    pub username: String,
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    pub locked: bool,
+    pub disabled: bool,
+}
+
+/// Request body for updating a user.
+/// Only `user_id` is required; all other fields are optional
+/// and only sent when set (via `#[serde(skip_serializing_if)]`).
+#[derive(Serialize)]
+pub struct UpdateUserRequest {
+    pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+impl KasmClient {
+    pub fn get_users(&self) -> Result<Vec<User>> {
+        #[derive(Serialize)]
+        struct Req {}
+
+        #[derive(Deserialize)]
+        struct Resp {
+            users: Vec<User>,
+        }
+
+        let resp: Resp = self.post("admin/get_users", &Req {})?;
+        Ok(resp.users)
+    }
+
+    pub fn get_user(&self, user_id: &str) -> Result<User> {
+        #[derive(Serialize)]
+        struct TargetUser<'a> {
+            user_id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_user: TargetUser<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            user: User,
+        }
+
+        let resp: Resp = self.post(
+            "admin/get_user",
+            &Req {
+                target_user: TargetUser { user_id },
+            },
+        )?;
+        Ok(resp.user)
+    }
+
+    pub fn create_user(&self, params: &CreateUserParams) -> Result<User> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_user: &'a CreateUserParams,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            user: User,
+        }
+
+        let resp: Resp = self.post(
+            "admin/create_user",
+            &Req {
+                target_user: params,
+            },
+        )?;
+        Ok(resp.user)
+    }
+
+    pub fn update_user(&self, req: &UpdateUserRequest) -> Result<User> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_user: &'a UpdateUserRequest,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            user: User,
+        }
+
+        let resp: Resp = self.post("admin/update_user", &Req { target_user: req })?;
+        Ok(resp.user)
+    }
+
+    /// Resolve an identifier to a [`User`].
+    ///
+    /// Matches an exact `user_id`, then a `user_id` prefix, then a
+    /// case-insensitive `username`, in the same way as [`KasmClient::resolve_image`].
+    pub fn resolve_user(&self, identifier: &str) -> Result<User> {
+        let users = self.get_users()?;
+        resolve_identifier(
+            users,
+            identifier,
+            "user",
+            |u| &u.user_id,
+            |u| u.username.as_deref(),
+        )
+    }
+
+    /// Delete a user. With `force`, the user is removed even if they still own sessions.
+    pub fn delete_user(&self, user_id: &str, force: bool) -> Result<()> {
+        #[derive(Serialize)]
+        struct TargetUser<'a> {
+            user_id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_user: TargetUser<'a>,
+            force: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {}
+
+        let _: Resp = self.post(
+            "admin/delete_user",
+            &Req {
+                target_user: TargetUser { user_id },
+                force,
+            },
+        )?;
+        Ok(())
+    }
+}
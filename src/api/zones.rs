@@ -3,7 +3,59 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::zone::Zone;
 
-use super::KasmClient;
+use super::{KasmClient, resolve_identifier};
+
+/// Parameters for creating a new zone.
+#[derive(Serialize)]
+pub struct CreateZoneParams {
+    pub zone_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_origin_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_auth_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_balancing_strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_alternate_zones: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prioritize_static_agents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_connections: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_hostname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_port: Option<i32>,
+}
+
+/// Request body for updating a zone.
+/// Only `zone_id` is required; all other fields are optional
+/// and only sent when set (via `#[serde(skip_serializing_if)]`).
+#[derive(Serialize)]
+pub struct UpdateZoneRequest {
+    pub zone_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_origin_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_auth_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_balancing_strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_alternate_zones: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prioritize_static_agents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_connections: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_hostname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_port: Option<i32>,
+}
 
 impl KasmClient {
     pub fn get_zones(&self) -> Result<Vec<Zone>> {
@@ -18,4 +70,77 @@ impl KasmClient {
         let resp: Resp = self.post("public/get_zones", &Req {})?;
         Ok(resp.zones)
     }
+
+    pub fn create_zone(&self, params: &CreateZoneParams) -> Result<Zone> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_zone: &'a CreateZoneParams,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            zone: Zone,
+        }
+
+        let resp: Resp = self.post(
+            "admin/create_zone",
+            &Req {
+                target_zone: params,
+            },
+        )?;
+        Ok(resp.zone)
+    }
+
+    pub fn update_zone(&self, req: &UpdateZoneRequest) -> Result<Zone> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_zone: &'a UpdateZoneRequest,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {
+            zone: Zone,
+        }
+
+        let resp: Resp = self.post("admin/update_zone", &Req { target_zone: req })?;
+        Ok(resp.zone)
+    }
+
+    pub fn delete_zone(&self, zone_id: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct TargetZone<'a> {
+            zone_id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Req<'a> {
+            target_zone: TargetZone<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct Resp {}
+
+        let _: Resp = self.post(
+            "admin/delete_zone",
+            &Req {
+                target_zone: TargetZone { zone_id },
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Resolve an identifier to a [`Zone`].
+    ///
+    /// Matches an exact `zone_id`, then a `zone_id` prefix, then a
+    /// case-insensitive `zone_name`, in the same way as [`KasmClient::resolve_image`].
+    pub fn resolve_zone(&self, identifier: &str) -> Result<Zone> {
+        let zones = self.get_zones()?;
+        resolve_identifier(
+            zones,
+            identifier,
+            "zone",
+            |z| &z.zone_id,
+            |z| z.zone_name.as_deref(),
+        )
+    }
 }
@@ -0,0 +1,515 @@
+//! Bounded worker pool for the bulk session commands (`stop sessions`,
+//! `pause sessions`, `resume sessions`, `delete sessions`, `exec sessions`,
+//! `screenshot sessions`, `keepalive sessions`).
+//!
+//! Up to `--parallel` items are processed at once, but results are always
+//! reported in input order so the output is the same as a sequential run.
+//! Table output prints a progress line per session as it completes; other
+//! output formats get a [`BulkResult`] document at the end instead, or with
+//! `-o name` the sessions that succeeded.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::api::error::ApiError;
+use crate::output::{self, OutputFormat};
+
+/// The operation a bulk command performs on each session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkAction {
+    Stop,
+    Pause,
+    Resume,
+    Delete,
+    Exec,
+    Screenshot,
+    Keepalive,
+}
+
+impl BulkAction {
+    /// Start of the confirmation prompt, e.g. `Stop` in "Stop 3 matching sessions?".
+    pub fn prompt(self) -> &'static str {
+        match self {
+            BulkAction::Stop => "Stop",
+            BulkAction::Pause => "Pause",
+            BulkAction::Resume => "Resume",
+            BulkAction::Delete => "Delete",
+            BulkAction::Exec => "Execute command on",
+            BulkAction::Screenshot => "Screenshot",
+            BulkAction::Keepalive => "Keep alive",
+        }
+    }
+
+    fn infinitive(self) -> &'static str {
+        match self {
+            BulkAction::Stop => "stop",
+            BulkAction::Pause => "pause",
+            BulkAction::Resume => "resume",
+            BulkAction::Delete => "delete",
+            BulkAction::Exec => "execute",
+            BulkAction::Screenshot => "screenshot",
+            BulkAction::Keepalive => "keep alive",
+        }
+    }
+}
+
+/// What happened to one item of a bulk operation.
+#[derive(Debug)]
+pub enum Outcome {
+    Ok,
+    /// Succeeded with something to report, e.g. a session's new expiration date.
+    Done(String),
+    Skipped(String),
+    Failed(anyhow::Error),
+    DryRun,
+}
+
+impl From<Result<()>> for Outcome {
+    fn from(result: Result<()>) -> Self {
+        match result {
+            Ok(()) => Outcome::Ok,
+            Err(e) if ApiError::is_dry_run(&e) => Outcome::DryRun,
+            Err(e) => Outcome::Failed(e),
+        }
+    }
+}
+
+impl From<Result<String>> for Outcome {
+    fn from(result: Result<String>) -> Self {
+        match result {
+            Ok(detail) => Outcome::Done(detail),
+            Err(e) => Outcome::from(Err::<(), _>(e)),
+        }
+    }
+}
+
+/// Counts for the summary of a bulk operation.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Tally {
+    pub total: usize,
+    pub succeeded: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+impl Tally {
+    /// Items that were not skipped.
+    pub fn attempted(&self) -> usize {
+        self.total - self.skipped
+    }
+
+    fn record(&mut self, outcome: &Outcome) {
+        self.total += 1;
+        match outcome {
+            Outcome::Ok | Outcome::Done(_) => self.succeeded += 1,
+            Outcome::Skipped(_) => self.skipped += 1,
+            Outcome::Failed(_) => self.failed += 1,
+            Outcome::DryRun => {}
+        }
+    }
+}
+
+/// The result of one session in a [`BulkResult`].
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct SessionResult {
+    pub kasm_id: String,
+    pub action: BulkAction,
+    /// `ok`, `skipped`, `failed`, or `dry_run`.
+    pub outcome: &'static str,
+    pub reason: Option<String>,
+    pub error: Option<String>,
+    /// What a successful action reported, e.g. a keepalive's new expiration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl SessionResult {
+    fn new(kasm_id: &str, action: BulkAction, outcome: &Outcome) -> Self {
+        let (label, reason, error) = match outcome {
+            Outcome::Ok | Outcome::Done(_) => ("ok", None, None),
+            Outcome::Skipped(reason) => ("skipped", Some(reason.clone()), None),
+            Outcome::Failed(e) => ("failed", None, Some(e.to_string())),
+            Outcome::DryRun => ("dry_run", None, None),
+        };
+        let detail = match outcome {
+            Outcome::Done(detail) => Some(detail.clone()),
+            _ => None,
+        };
+        Self {
+            kasm_id: kasm_id.to_string(),
+            action,
+            outcome: label,
+            reason,
+            error,
+            detail,
+        }
+    }
+}
+
+/// Machine-readable result of a bulk command, for `-o json` and `-o yaml`.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct BulkResult {
+    pub action: BulkAction,
+    pub sessions: Vec<SessionResult>,
+    pub totals: Tally,
+}
+
+impl BulkResult {
+    pub fn new(action: BulkAction) -> Self {
+        Self {
+            action,
+            sessions: Vec::new(),
+            totals: Tally::default(),
+        }
+    }
+
+    /// E.g. `Stopped 9/10 sessions. (2 skipped)`.
+    pub fn summary(&self) -> String {
+        let totals = &self.totals;
+        let verb = match self.action {
+            BulkAction::Stop => "Stopped",
+            BulkAction::Pause => "Paused",
+            BulkAction::Resume => "Resumed",
+            BulkAction::Delete => "Deleted",
+            BulkAction::Exec => "Executed on",
+            BulkAction::Screenshot => "Saved screenshots of",
+            BulkAction::Keepalive => "Kept alive",
+        };
+        let skipped = if totals.skipped > 0 {
+            format!(" ({} skipped)", totals.skipped)
+        } else {
+            String::new()
+        };
+        format!(
+            "{verb} {}/{} sessions.{skipped}",
+            totals.attempted() - totals.failed,
+            totals.attempted()
+        )
+    }
+
+    /// E.g. `Dry run: 3 session(s) would be stopped.`
+    pub fn dry_run_summary(&self) -> String {
+        let attempted = self.totals.attempted();
+        let done = match self.action {
+            BulkAction::Exec => {
+                return format!("Dry run: the command would run on {attempted} session(s).");
+            }
+            BulkAction::Stop => "stopped",
+            BulkAction::Pause => "paused",
+            BulkAction::Resume => "resumed",
+            BulkAction::Delete => "deleted",
+            BulkAction::Screenshot => "captured",
+            BulkAction::Keepalive => "kept alive",
+        };
+        format!("Dry run: {attempted} session(s) would be {done}.")
+    }
+
+    /// Render the result for a non-table output format. `-o name` prints
+    /// `session/<kasm_id>` for every session the action succeeded on.
+    ///
+    /// Rendering depends only on the format, so rendering an empty result
+    /// first tells whether the format is supported before anything is changed.
+    pub fn render(&self, format: &OutputFormat) -> Result<String> {
+        match format {
+            OutputFormat::Name => Ok(self
+                .sessions
+                .iter()
+                .filter(|s| s.outcome == "ok")
+                .map(|s| format!("session/{}", s.kasm_id))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            _ => output::render_value(self, format),
+        }
+    }
+
+    /// Fail when any session failed, e.g. `2 session(s) failed to stop`.
+    pub fn check(&self) -> Result<()> {
+        if self.totals.failed > 0 {
+            anyhow::bail!(
+                "{} session(s) failed to {}",
+                self.totals.failed,
+                self.action.infinitive()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Run `op` on every session with at most `parallel` calls in flight.
+///
+/// With `progress`, an `ok`/`skipped`/`FAILED` line is printed to stderr for
+/// each session, in input order.
+pub fn run<T, I, F>(
+    action: BulkAction,
+    items: &[T],
+    parallel: NonZeroUsize,
+    progress: bool,
+    id: I,
+    op: F,
+) -> BulkResult
+where
+    T: Sync,
+    I: Fn(&T) -> &str,
+    F: Fn(&T) -> Outcome + Sync,
+{
+    let mut result = BulkResult::new(action);
+    for_each_ordered(items, parallel, &op, |index, outcome| {
+        let id = id(&items[index]);
+        if progress {
+            match &outcome {
+                Outcome::Ok => eprintln!("  {id} ok"),
+                Outcome::Done(detail) => eprintln!("  {id} ok ({detail})"),
+                Outcome::Skipped(reason) => eprintln!("  {id} skipped ({reason})"),
+                Outcome::Failed(e) => eprintln!("  {id} FAILED: {e}"),
+                Outcome::DryRun => eprintln!("  {id} dry run"),
+            }
+        }
+        result.totals.record(&outcome);
+        result
+            .sessions
+            .push(SessionResult::new(id, action, &outcome));
+    });
+    result
+}
+
+/// Apply `op` to every item on a scoped worker pool and hand each result to
+/// `report` in input order, as soon as all earlier results are in.
+fn for_each_ordered<T, R, F>(
+    items: &[T],
+    parallel: NonZeroUsize,
+    op: &F,
+    mut report: impl FnMut(usize, R),
+) where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = parallel.get().min(items.len());
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let next = &next;
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else { break };
+                    if tx.send((index, op(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+        for (index, result) in rx {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&expected) {
+                report(expected, result);
+                expected += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn n(value: usize) -> NonZeroUsize {
+        NonZeroUsize::new(value).unwrap()
+    }
+
+    #[test]
+    fn results_are_reported_in_input_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let mut seen = Vec::new();
+        // Earlier items take longer, so they finish last.
+        for_each_ordered(
+            &items,
+            n(8),
+            &|item: &u64| {
+                thread::sleep(Duration::from_millis(20 - item));
+                *item * 10
+            },
+            |index, result| seen.push((index, result)),
+        );
+        let expected: Vec<(usize, u64)> = (0..20).map(|i| (i as usize, i * 10)).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn at_most_parallel_calls_run_at_once() {
+        let items: Vec<usize> = (0..12).collect();
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        for_each_ordered(
+            &items,
+            n(3),
+            &|_: &usize| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(10));
+                running.fetch_sub(1, Ordering::SeqCst);
+            },
+            |_, ()| {},
+        );
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert!(peak.load(Ordering::SeqCst) > 1);
+    }
+
+    fn stop_all(items: &[&str]) -> BulkResult {
+        run(
+            BulkAction::Stop,
+            items,
+            n(2),
+            false,
+            |s| s,
+            |s| match *s {
+                "a" => Outcome::Skipped("stopped".into()),
+                "b" => Outcome::Failed(anyhow::anyhow!("boom")),
+                _ => Outcome::Ok,
+            },
+        )
+    }
+
+    #[test]
+    fn tally_counts_outcomes() {
+        let result = stop_all(&["a", "b", "c", "d"]);
+        assert_eq!(
+            result.totals,
+            Tally {
+                total: 4,
+                succeeded: 2,
+                skipped: 1,
+                failed: 1,
+            }
+        );
+        assert_eq!(result.summary(), "Stopped 2/3 sessions. (1 skipped)");
+        let err = result.check().unwrap_err();
+        assert_eq!(err.to_string(), "1 session(s) failed to stop");
+    }
+
+    #[test]
+    fn result_document_lists_each_session_in_order() {
+        let result = stop_all(&["a", "b", "c"]);
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "action": "stop",
+                "sessions": [
+                    {"kasm_id": "a", "action": "stop", "outcome": "skipped", "reason": "stopped", "error": null},
+                    {"kasm_id": "b", "action": "stop", "outcome": "failed", "reason": null, "error": "boom"},
+                    {"kasm_id": "c", "action": "stop", "outcome": "ok", "reason": null, "error": null},
+                ],
+                "totals": {"total": 3, "succeeded": 1, "skipped": 1, "failed": 1},
+            })
+        );
+    }
+
+    #[test]
+    fn empty_input_runs_nothing() {
+        let items: [&str; 0] = [];
+        let result = run(
+            BulkAction::Pause,
+            &items,
+            n(4),
+            false,
+            |s| s,
+            |_| Outcome::Ok,
+        );
+        assert_eq!(result, BulkResult::new(BulkAction::Pause));
+        assert_eq!(result.summary(), "Paused 0/0 sessions.");
+        assert!(result.check().is_ok());
+    }
+
+    #[test]
+    fn summaries_name_the_action() {
+        let mut result = BulkResult::new(BulkAction::Exec);
+        result.totals.total = 2;
+        assert_eq!(result.summary(), "Executed on 2/2 sessions.");
+        assert_eq!(
+            result.dry_run_summary(),
+            "Dry run: the command would run on 2 session(s)."
+        );
+        result.action = BulkAction::Delete;
+        assert_eq!(
+            result.dry_run_summary(),
+            "Dry run: 2 session(s) would be deleted."
+        );
+        result.action = BulkAction::Screenshot;
+        assert_eq!(result.summary(), "Saved screenshots of 2/2 sessions.");
+        assert_eq!(
+            result.dry_run_summary(),
+            "Dry run: 2 session(s) would be captured."
+        );
+    }
+
+    #[test]
+    fn details_are_reported_only_when_present() {
+        let result = run(
+            BulkAction::Keepalive,
+            &["a", "b"],
+            n(2),
+            false,
+            |s| s,
+            |s| match *s {
+                "a" => Outcome::from(Ok("expires 2026-10-17 18:00:00".to_string())),
+                _ => Outcome::Ok,
+            },
+        );
+        assert_eq!(result.totals.succeeded, 2);
+        assert_eq!(result.summary(), "Kept alive 2/2 sessions.");
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            value["sessions"][0]["detail"],
+            "expires 2026-10-17 18:00:00"
+        );
+        assert!(value["sessions"][1].get("detail").is_none());
+    }
+
+    #[test]
+    fn name_output_lists_sessions_that_succeeded() {
+        let result = stop_all(&["a", "b", "c", "d"]);
+        assert_eq!(
+            result.render(&OutputFormat::Name).unwrap(),
+            "session/c\nsession/d"
+        );
+    }
+
+    #[test]
+    fn every_structured_format_renders_an_empty_result() {
+        for format in [
+            "name",
+            "json",
+            "yaml",
+            "csv",
+            "tsv",
+            "jsonpath={.totals.total}",
+        ] {
+            let format: OutputFormat = format.parse().unwrap();
+            assert!(BulkResult::new(BulkAction::Stop).render(&format).is_ok());
+        }
+    }
+
+    #[test]
+    fn dry_run_errors_are_not_failures() {
+        let outcome = Outcome::from(Err::<(), _>(ApiError::DryRun("stop_kasm".into()).into()));
+        assert!(matches!(outcome, Outcome::DryRun));
+        let outcome = Outcome::from(Err::<(), _>(anyhow::anyhow!("boom")));
+        assert!(matches!(outcome, Outcome::Failed(_)));
+    }
+}
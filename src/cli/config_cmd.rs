@@ -28,6 +28,18 @@ pub enum ConfigCommand {
         /// Skip TLS certificate verification (for self-signed certificates)
         #[arg(long, default_value_t = false, action = clap::ArgAction::Set)]
         insecure: bool,
+
+        /// CA certificates to trust for the server (PEM file), instead of the built-in roots
+        #[arg(long, value_name = "PATH")]
+        certificate_authority: Option<String>,
+
+        /// Client certificate for mutual TLS (PEM file)
+        #[arg(long, value_name = "PATH", requires = "client_key")]
+        client_certificate: Option<String>,
+
+        /// Private key for --client-certificate (PEM file)
+        #[arg(long, value_name = "PATH", requires = "client_certificate")]
+        client_key: Option<String>,
     },
 
     /// Switch the active context
@@ -2,10 +2,13 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use clap::Args;
 
+use crate::cli::selector::FieldSelector;
 use crate::models::agent::Agent;
+use crate::models::group::Group;
 use crate::models::image::Image;
 use crate::models::server::Server;
 use crate::models::session::Session;
+use crate::models::user::User;
 use crate::models::zone::Zone;
 
 /// Shared filter options for bulk session commands.
@@ -42,6 +45,10 @@ pub struct SessionFilters {
     /// Only sessions idle for at least this duration (e.g. 30m, 2h, 1d, 1h30m)
     #[arg(long, value_name = "DURATION")]
     pub idle_for: Option<String>,
+
+    /// Filter by field expressions (e.g. operational_status!=running,hostname=~^agent-0[1-3])
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<Session>)]
+    pub field_selector: Option<FieldSelector>,
 }
 
 impl SessionFilters {
@@ -55,6 +62,7 @@ impl SessionFilters {
             && self.created_after.is_none()
             && self.idle_since.is_none()
             && self.idle_for.is_none()
+            && self.field_selector.is_none()
     }
 
     /// Validate filter inputs before making any API calls.
@@ -134,6 +142,12 @@ impl SessionFilters {
                 return false;
             }
 
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(s)
+            {
+                return false;
+            }
+
             true
         });
         Ok(())
@@ -158,12 +172,20 @@ pub struct ImageFilters {
     /// Filter by image type / source
     #[arg(long)]
     pub image_type: Option<String>,
+
+    /// Filter by field expressions (e.g. cores>=2,memory>2GB)
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<Image>)]
+    pub field_selector: Option<FieldSelector>,
 }
 
 impl ImageFilters {
     /// Returns true when no filters are set.
     pub fn is_empty(&self) -> bool {
-        !self.enabled && !self.disabled && self.name.is_none() && self.image_type.is_none()
+        !self.enabled
+            && !self.disabled
+            && self.name.is_none()
+            && self.image_type.is_none()
+            && self.field_selector.is_none()
     }
 
     /// Apply all filters to a list of images, removing non-matching entries.
@@ -198,6 +220,12 @@ impl ImageFilters {
                 return false;
             }
 
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(img)
+            {
+                return false;
+            }
+
             true
         });
     }
@@ -209,12 +237,16 @@ pub struct ZoneFilters {
     /// Filter by zone name (case-insensitive substring match)
     #[arg(long)]
     pub name: Option<String>,
+
+    /// Filter by field expressions (e.g. proxy_port!=443)
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<Zone>)]
+    pub field_selector: Option<FieldSelector>,
 }
 
 impl ZoneFilters {
     /// Returns true when no filters are set.
     pub fn is_empty(&self) -> bool {
-        self.name.is_none()
+        self.name.is_none() && self.field_selector.is_none()
     }
 
     /// Apply all filters to a list of zones, removing non-matching entries.
@@ -230,6 +262,12 @@ impl ZoneFilters {
                 return false;
             }
 
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(z)
+            {
+                return false;
+            }
+
             true
         });
     }
@@ -253,12 +291,20 @@ pub struct AgentFilters {
     /// Filter by agent status (case-insensitive)
     #[arg(long)]
     pub status: Option<String>,
+
+    /// Filter by field expressions (e.g. hostname=~^gpu-,memory>=32GB)
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<Agent>)]
+    pub field_selector: Option<FieldSelector>,
 }
 
 impl AgentFilters {
     /// Returns true when no filters are set.
     pub fn is_empty(&self) -> bool {
-        self.zone.is_none() && !self.enabled && !self.disabled && self.status.is_none()
+        self.zone.is_none()
+            && !self.enabled
+            && !self.disabled
+            && self.status.is_none()
+            && self.field_selector.is_none()
     }
 
     /// Apply all filters to a list of agents, removing non-matching entries.
@@ -288,6 +334,12 @@ impl AgentFilters {
                 return false;
             }
 
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(a)
+            {
+                return false;
+            }
+
             true
         });
     }
@@ -315,6 +367,10 @@ pub struct ServerFilters {
     /// Filter by friendly name (case-insensitive substring match)
     #[arg(long)]
     pub name: Option<String>,
+
+    /// Filter by field expressions (e.g. connection_type=RDP,max_simultaneous_users>1)
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<Server>)]
+    pub field_selector: Option<FieldSelector>,
 }
 
 impl ServerFilters {
@@ -325,6 +381,7 @@ impl ServerFilters {
             && !self.enabled
             && !self.disabled
             && self.name.is_none()
+            && self.field_selector.is_none()
     }
 
     /// Apply all filters to a list of servers, removing non-matching entries.
@@ -363,6 +420,146 @@ impl ServerFilters {
                 return false;
             }
 
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(s)
+            {
+                return false;
+            }
+
+            true
+        });
+    }
+}
+
+/// Shared filter options for user list commands.
+#[derive(Args, Clone, Debug, Default)]
+pub struct UserFilters {
+    /// Filter by username (case-insensitive substring match)
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Filter by authentication realm (case-insensitive, e.g. local, ldap, saml)
+    #[arg(long)]
+    pub realm: Option<String>,
+
+    /// Only show locked users
+    #[arg(long, conflicts_with = "unlocked")]
+    pub locked: bool,
+
+    /// Only show unlocked users
+    #[arg(long, conflicts_with = "locked")]
+    pub unlocked: bool,
+
+    /// Only show enabled users
+    #[arg(long, conflicts_with = "disabled")]
+    pub enabled: bool,
+
+    /// Only show disabled users
+    #[arg(long, conflicts_with = "enabled")]
+    pub disabled: bool,
+
+    /// Filter by field expressions (e.g. last_session<2024-01-01 00:00:00)
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<User>)]
+    pub field_selector: Option<FieldSelector>,
+}
+
+impl UserFilters {
+    /// Returns true when no filters are set.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.realm.is_none()
+            && !self.locked
+            && !self.unlocked
+            && !self.enabled
+            && !self.disabled
+            && self.field_selector.is_none()
+    }
+
+    /// Apply all filters to a list of users, removing non-matching entries.
+    pub fn apply(&self, users: &mut Vec<User>) {
+        let name_lower = self.name.as_ref().map(|n| n.to_lowercase());
+        let realm_lower = self.realm.as_ref().map(|r| r.to_lowercase());
+
+        users.retain(|u| {
+            if let Some(ref pattern) = name_lower
+                && u.username
+                    .as_ref()
+                    .is_none_or(|n| !n.to_lowercase().contains(pattern.as_str()))
+            {
+                return false;
+            }
+
+            if let Some(ref realm) = realm_lower
+                && u.realm.as_ref().is_none_or(|r| r.to_lowercase() != *realm)
+            {
+                return false;
+            }
+
+            if self.locked && u.locked != Some(true) {
+                return false;
+            }
+
+            // Users without an explicit flag are treated as unlocked / enabled.
+            if self.unlocked && u.locked == Some(true) {
+                return false;
+            }
+
+            if self.enabled && u.disabled == Some(true) {
+                return false;
+            }
+
+            if self.disabled && u.disabled != Some(true) {
+                return false;
+            }
+
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(u)
+            {
+                return false;
+            }
+
+            true
+        });
+    }
+}
+
+/// Shared filter options for group list commands.
+#[derive(Args, Clone, Debug, Default)]
+pub struct GroupFilters {
+    /// Filter by group name (case-insensitive substring match)
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Filter by field expressions (e.g. priority<=10)
+    #[arg(long, value_name = "SELECTOR", value_parser = FieldSelector::parser::<Group>)]
+    pub field_selector: Option<FieldSelector>,
+}
+
+impl GroupFilters {
+    /// Returns true when no filters are set.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.field_selector.is_none()
+    }
+
+    /// Apply all filters to a list of groups, removing non-matching entries.
+    pub fn apply(&self, groups: &mut Vec<Group>) {
+        let name_lower = self.name.as_ref().map(|n| n.to_lowercase());
+
+        groups.retain(|g| {
+            if let Some(ref pattern) = name_lower
+                && g.name
+                    .as_ref()
+                    .is_none_or(|n| !n.to_lowercase().contains(pattern.as_str()))
+            {
+                return false;
+            }
+
+            if let Some(ref selector) = self.field_selector
+                && !selector.matches(g)
+            {
+                return false;
+            }
+
             true
         });
     }
@@ -407,6 +604,32 @@ pub fn parse_memory(s: &str) -> Result<i64, String> {
     Ok(bytes)
 }
 
+/// Parse a polling interval such as `200ms`, `2s`, `0.5s`, `1m`, or `1h30m`.
+///
+/// Milliseconds use the `ms` suffix and seconds (optionally fractional) the
+/// `s` suffix; anything else is handled by the same parser as `--idle-since`.
+pub fn parse_interval(s: &str) -> Result<Duration, String> {
+    if let Some(ms) = s.strip_suffix("ms") {
+        let ms: u64 = ms
+            .parse()
+            .map_err(|_| format!("invalid number in interval {s:?}"))?;
+        if ms == 0 {
+            return Err(format!("interval must be greater than zero: {s:?}"));
+        }
+        return Ok(Duration::from_millis(ms));
+    }
+    if let Some(secs) = s.strip_suffix('s') {
+        let secs: f64 = secs
+            .parse()
+            .map_err(|_| format!("invalid number in interval {s:?}"))?;
+        if !secs.is_finite() || secs <= 0.0 {
+            return Err(format!("interval must be greater than zero: {s:?}"));
+        }
+        return Ok(Duration::from_secs_f64(secs));
+    }
+    parse_duration(s).map(Duration::from_secs)
+}
+
 /// Parse a human-friendly duration string into total seconds.
 ///
 /// Supports combinations like `30m`, `2h`, `1d`, `1h30m`, `1d12h`.
@@ -462,7 +685,7 @@ fn parse_duration(s: &str) -> Result<u64, String> {
 }
 
 /// Return the current UTC time minus `secs` seconds, formatted as `YYYY-MM-DD HH:MM:SS`.
-fn format_utc_minus(secs: u64) -> String {
+pub(crate) fn format_utc_minus(secs: u64) -> String {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or(Duration::ZERO);
@@ -705,6 +928,33 @@ mod tests {
         assert_eq!(sessions[0].image_id.as_deref(), Some("img-1"));
     }
 
+    #[test]
+    fn filter_field_selector_composes_with_other_filters() {
+        let filters = SessionFilters {
+            status: Some("running".into()),
+            field_selector: Some("hostname=~^agent-0[1-3]$".parse().unwrap()),
+            ..Default::default()
+        };
+        let mut sessions = vec![
+            make_session(|s| {
+                s.operational_status = Some("running".into());
+                s.hostname = Some("agent-02".into());
+            }),
+            make_session(|s| {
+                s.operational_status = Some("running".into());
+                s.hostname = Some("agent-07".into());
+            }),
+            make_session(|s| {
+                s.operational_status = Some("stopped".into());
+                s.hostname = Some("agent-01".into());
+            }),
+        ];
+        filters.apply(&mut sessions).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].hostname.as_deref(), Some("agent-02"));
+        assert!(!filters.is_empty());
+    }
+
     #[test]
     fn filter_no_filters_retains_all() {
         let filters = SessionFilters::default();
@@ -838,6 +1088,7 @@ mod tests {
             cores: Some(2.0),
             memory: Some(2_147_483_648),
             image_src: Some("Container".into()),
+            ..Default::default()
         };
         overrides(&mut img);
         img
@@ -1011,6 +1262,24 @@ mod tests {
         assert_eq!(images[0].friendly_name.as_deref(), Some("Ubuntu 22.04"));
     }
 
+    #[test]
+    fn image_filter_by_field_selector() {
+        let filters = ImageFilters {
+            field_selector: Some("memory>2GB".parse().unwrap()),
+            ..Default::default()
+        };
+        let mut images = vec![
+            make_image(|_| {}),
+            make_image(|i| {
+                i.image_id = "img-2".into();
+                i.memory = Some(4_294_967_296);
+            }),
+        ];
+        filters.apply(&mut images);
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].image_id, "img-2");
+    }
+
     #[test]
     fn image_filter_no_filters_retains_all() {
         let filters = ImageFilters::default();
@@ -1019,13 +1288,152 @@ mod tests {
         assert_eq!(images.len(), 3);
     }
 
+    // --- UserFilters apply() tests ---
+
+    fn make_user(overrides: impl FnOnce(&mut User)) -> User {
+        let mut user = User {
+            user_id: "user-1".into(),
+            username: Some("alice@example.com".into()),
+            realm: Some("local".into()),
+            locked: Some(false),
+            disabled: Some(false),
+            ..Default::default()
+        };
+        overrides(&mut user);
+        user
+    }
+
+    #[test]
+    fn user_filter_by_name_case_insensitive_substring() {
+        let filters = UserFilters {
+            name: Some("ALICE".into()),
+            ..Default::default()
+        };
+        let mut users = vec![
+            make_user(|_| {}),
+            make_user(|u| u.username = Some("bob@example.com".into())),
+            make_user(|u| u.username = None),
+        ];
+        filters.apply(&mut users);
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].username.as_deref(), Some("alice@example.com"));
+    }
+
+    #[test]
+    fn user_filter_by_realm() {
+        let filters = UserFilters {
+            realm: Some("LDAP".into()),
+            ..Default::default()
+        };
+        let mut users = vec![
+            make_user(|_| {}),
+            make_user(|u| u.realm = Some("ldap".into())),
+        ];
+        filters.apply(&mut users);
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].realm.as_deref(), Some("ldap"));
+    }
+
+    #[test]
+    fn user_filter_by_locked() {
+        let filters = UserFilters {
+            locked: true,
+            ..Default::default()
+        };
+        let mut users = vec![
+            make_user(|u| u.locked = Some(true)),
+            make_user(|u| u.locked = Some(false)),
+            make_user(|u| u.locked = None),
+        ];
+        filters.apply(&mut users);
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].locked, Some(true));
+    }
+
+    #[test]
+    fn user_filter_unlocked_includes_missing_flag() {
+        let filters = UserFilters {
+            unlocked: true,
+            ..Default::default()
+        };
+        let mut users = vec![
+            make_user(|u| u.locked = Some(true)),
+            make_user(|u| u.locked = Some(false)),
+            make_user(|u| u.locked = None),
+        ];
+        filters.apply(&mut users);
+        assert_eq!(users.len(), 2);
+    }
+
+    #[test]
+    fn user_filter_by_disabled() {
+        let filters = UserFilters {
+            disabled: true,
+            ..Default::default()
+        };
+        let mut users = vec![
+            make_user(|u| u.disabled = Some(true)),
+            make_user(|u| u.disabled = Some(false)),
+        ];
+        filters.apply(&mut users);
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].disabled, Some(true));
+    }
+
+    #[test]
+    fn user_filter_no_filters_retains_all() {
+        let filters = UserFilters::default();
+        assert!(filters.is_empty());
+        let mut users = vec![make_user(|_| {}), make_user(|_| {})];
+        filters.apply(&mut users);
+        assert_eq!(users.len(), 2);
+    }
+
+    // --- GroupFilters apply() tests ---
+
+    #[test]
+    fn group_filter_by_name_case_insensitive_substring() {
+        let filters = GroupFilters {
+            name: Some("ENG".into()),
+            ..Default::default()
+        };
+        let mut groups = vec![
+            Group {
+                group_id: "grp-1".into(),
+                name: Some("Engineering".into()),
+                ..Default::default()
+            },
+            Group {
+                group_id: "grp-2".into(),
+                name: Some("All Users".into()),
+                ..Default::default()
+            },
+            Group {
+                group_id: "grp-3".into(),
+                ..Default::default()
+            },
+        ];
+        filters.apply(&mut groups);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].group_id, "grp-1");
+    }
+
+    #[test]
+    fn group_filter_no_filters_retains_all() {
+        let filters = GroupFilters::default();
+        assert!(filters.is_empty());
+        let mut groups = vec![Group::default(), Group::default()];
+        filters.apply(&mut groups);
+        assert_eq!(groups.len(), 2);
+    }
+
     proptest! {
         #[test]
         fn epoch_to_datetime_produces_valid_components(epoch in 0u64..=253_402_300_799u64) {
             let (year, month, day, hour, min, sec) = epoch_to_datetime(epoch);
-            prop_assert!(year >= 1970 && year <= 9999);
-            prop_assert!(month >= 1 && month <= 12);
-            prop_assert!(day >= 1 && day <= 31);
+            prop_assert!((1970..=9999).contains(&year));
+            prop_assert!((1..=12).contains(&month));
+            prop_assert!((1..=31).contains(&day));
             prop_assert!(hour <= 23);
             prop_assert!(min <= 59);
             prop_assert!(sec <= 59);
@@ -1042,6 +1450,24 @@ mod tests {
         assert!(filters.apply(&mut sessions).is_err());
     }
 
+    #[test]
+    fn parse_interval_accepts_seconds_and_minutes() {
+        assert_eq!(parse_interval("2s").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_interval("0.5s").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_interval("1m").unwrap(), Duration::from_secs(60));
+        assert_eq!(parse_interval("250ms").unwrap(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn parse_interval_rejects_zero_and_garbage() {
+        assert!(parse_interval("0s").is_err());
+        assert!(parse_interval("0ms").is_err());
+        assert!(parse_interval("1.5ms").is_err());
+        assert!(parse_interval("-1s").is_err());
+        assert!(parse_interval("fast").is_err());
+        assert!(parse_interval("5").is_err());
+    }
+
     #[test]
     fn parse_duration_rejects_overflow() {
         assert!(parse_duration("999999999999999999d").is_err());
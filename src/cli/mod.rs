@@ -1,21 +1,37 @@
 pub mod config_cmd;
 pub mod filters;
+pub mod selector;
+pub mod sort;
 pub mod verbs;
 
-use clap::Parser;
+use std::time::Duration;
+
+use clap::{ArgAction, Args, Parser};
 use clap_complete::Shell;
 
+use crate::config::model::RetryConfig;
 use crate::output::OutputFormat;
 
 use self::config_cmd::ConfigArgs;
+use self::verbs::add::AddArgs;
+use self::verbs::apply::ApplyArgs;
 use self::verbs::create::CreateArgs;
 use self::verbs::delete::DeleteArgs;
+use self::verbs::diff::DiffArgs;
 use self::verbs::exec::ExecArgs;
+use self::verbs::export::ExportArgs;
 use self::verbs::get::GetArgs;
+use self::verbs::join::JoinArgs;
+use self::verbs::keepalive::KeepaliveArgs;
 use self::verbs::pause::PauseArgs;
+use self::verbs::remove::RemoveArgs;
 use self::verbs::resume::ResumeArgs;
+use self::verbs::screenshot::ScreenshotArgs;
+use self::verbs::share::ShareArgs;
+use self::verbs::stats::StatsArgs;
 use self::verbs::stop::StopArgs;
 use self::verbs::top::TopArgs;
+use self::verbs::unshare::UnshareArgs;
 use self::verbs::update::UpdateArgs;
 
 #[derive(Parser)]
@@ -24,10 +40,14 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Command,
 
-    /// Output format
+    /// Output format: table, wide, json, yaml, csv, tsv, name, jsonpath=<TEMPLATE>, or custom-columns=<SPEC>
     #[arg(short, long, global = true, default_value = "table")]
     pub output: OutputFormat,
 
+    /// Use every field as a column in csv/tsv output instead of the table columns
+    #[arg(long, global = true, default_value_t = false)]
+    pub all_fields: bool,
+
     /// Override the active context
     #[arg(long, global = true)]
     pub context: Option<String>,
@@ -39,6 +59,76 @@ pub struct Cli {
     /// Skip TLS certificate verification (for self-signed certificates)
     #[arg(long, global = true, default_value_t = false)]
     pub insecure: bool,
+
+    /// CA certificates to trust for the server (PEM file), instead of the built-in roots
+    #[arg(long, global = true, value_name = "PATH")]
+    pub certificate_authority: Option<String>,
+
+    /// Print the requests that would change state instead of sending them
+    #[arg(long, global = true, default_value_t = false)]
+    pub dry_run: bool,
+
+    /// Trace API requests to stderr: -v for status and latency, -vv to add redacted bodies
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+/// Overrides for the context's retry policy.
+#[derive(Args, Default)]
+pub struct RetryArgs {
+    /// Retries for transient failures (0 disables retrying)
+    #[arg(long, global = true, value_name = "N")]
+    pub retries: Option<u32>,
+
+    /// Delay before the first retry, doubled for each further retry (e.g. 200ms, 1s)
+    #[arg(long, global = true, value_name = "DURATION", value_parser = filters::parse_interval)]
+    pub retry_delay: Option<Duration>,
+
+    /// Upper bound for the delay between retries (e.g. 5s)
+    #[arg(long, global = true, value_name = "DURATION", value_parser = filters::parse_interval)]
+    pub retry_max_delay: Option<Duration>,
+
+    /// Wait exactly the computed delay instead of a random part of it
+    #[arg(long, global = true, default_value_t = false)]
+    pub no_retry_jitter: bool,
+
+    /// Also retry idempotent changes such as stop, pause, update, and delete
+    #[arg(long, global = true, default_value_t = false)]
+    pub retry_mutating: bool,
+}
+
+impl RetryArgs {
+    /// Apply the flags that were given on top of `retry`, starting from the
+    /// default policy when the context has none.
+    pub fn apply(&self, retry: &mut Option<RetryConfig>) {
+        if self.retries.is_none()
+            && self.retry_delay.is_none()
+            && self.retry_max_delay.is_none()
+            && !self.no_retry_jitter
+            && !self.retry_mutating
+        {
+            return;
+        }
+        let policy = retry.get_or_insert_with(RetryConfig::default);
+        if let Some(retries) = self.retries {
+            policy.max_retries = retries;
+        }
+        if let Some(delay) = self.retry_delay {
+            policy.base_delay_ms = delay.as_millis() as u64;
+        }
+        if let Some(delay) = self.retry_max_delay {
+            policy.max_delay_ms = delay.as_millis() as u64;
+        }
+        if self.no_retry_jitter {
+            policy.jitter = false;
+        }
+        if self.retry_mutating {
+            policy.retry_mutating = true;
+        }
+    }
 }
 
 #[derive(clap::Subcommand)]
@@ -55,10 +145,32 @@ pub enum Command {
     Pause(PauseArgs),
     /// Resume a stopped or paused session
     Resume(ResumeArgs),
+    /// Save screenshots of sessions to image files
+    Screenshot(ScreenshotArgs),
+    /// Show frame and bottleneck statistics of a session
+    Stats(StatsArgs),
+    /// Share a session so that other users can join it
+    Share(ShareArgs),
+    /// Stop sharing a session
+    Unshare(UnshareArgs),
+    /// Join a shared session by its share ID
+    Join(JoinArgs),
+    /// Keep a session alive, extending its expiration
+    Keepalive(KeepaliveArgs),
     /// Update a resource
     Update(UpdateArgs),
+    /// Create or update resources from YAML/JSON manifests
+    Apply(ApplyArgs),
+    /// Show differences between manifests and the live deployment
+    Diff(DiffArgs),
+    /// Export live resources as manifests that can be applied back
+    Export(ExportArgs),
     /// Execute a command inside a session
     Exec(ExecArgs),
+    /// Add a user to a group
+    Add(AddArgs),
+    /// Remove a user from a group
+    Remove(RemoveArgs),
     /// Manage configuration contexts
     Config(ConfigArgs),
     /// Generate shell completion scripts
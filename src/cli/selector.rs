@@ -0,0 +1,317 @@
+//! `--field-selector` expressions for list and bulk commands.
+//!
+//! A selector is a comma-separated list of requirements that must all hold,
+//! evaluated against the serialized form of a resource (the fields shown by
+//! `-o json`, with dots for nested fields):
+//!
+//! - `field=value`, `field==value`, `field!=value` — equality
+//! - `field=~regex`, `field!~regex` — regular expression (unanchored) match
+//! - `field>value`, `field>=value`, `field<value`, `field<=value` — ordering
+//!
+//! Values compare the same way as `--sort-by`: numbers, byte counts such as
+//! `2GB`, and `YYYY-MM-DD HH:MM:SS` datetimes by value, everything else as
+//! text. A missing or null field equals the empty string, so `share_id!=`
+//! selects shared sessions. Write `\,` for a literal comma inside a value.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::sort::{SortKey, lookup};
+
+/// A parsed field selector such as `operational_status!=running,memory>2GB`.
+#[derive(Debug, Clone)]
+pub struct FieldSelector {
+    source: String,
+    requirements: Vec<Requirement>,
+}
+
+#[derive(Debug, Clone)]
+struct Requirement {
+    field: String,
+    condition: Condition,
+}
+
+#[derive(Debug, Clone)]
+enum Condition {
+    Equals(String),
+    NotEquals(String),
+    Matches(Regex),
+    NotMatches(Regex),
+    Compare(Ordering, bool, String),
+}
+
+/// Operators, longest first so that `!=` is not read as `!` followed by `=`.
+const OPERATORS: [&str; 9] = ["==", "!=", "=~", "!~", ">=", "<=", "=", ">", "<"];
+
+impl FieldSelector {
+    /// Parse a selector and check that every field exists on `T`.
+    ///
+    /// Used as a clap value parser so that typos fail before any API call.
+    pub fn parser<T: Default + Serialize>(s: &str) -> Result<Self, String> {
+        let selector: Self = s.parse()?;
+        let template = serde_json::to_value(T::default()).map_err(|e| e.to_string())?;
+        for requirement in &selector.requirements {
+            if !has_field(&template, &requirement.field) {
+                return Err(format!(
+                    "unknown field {:?}; use a field name from `-o json` output",
+                    requirement.field
+                ));
+            }
+        }
+        Ok(selector)
+    }
+
+    /// Returns true when `item` satisfies every requirement.
+    pub fn matches<T: Serialize>(&self, item: &T) -> bool {
+        let Ok(value) = serde_json::to_value(item) else {
+            return false;
+        };
+        self.requirements.iter().all(|r| r.matches(&value))
+    }
+
+    /// Remove the items that do not match.
+    pub fn retain<T: Serialize>(&self, items: &mut Vec<T>) {
+        items.retain(|item| self.matches(item));
+    }
+}
+
+impl FromStr for FieldSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let requirements = split_unescaped(s)
+            .iter()
+            .map(|part| parse_requirement(part.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            source: s.to_string(),
+            requirements,
+        })
+    }
+}
+
+impl fmt::Display for FieldSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Requirement {
+    fn matches(&self, item: &Value) -> bool {
+        let value = lookup(item, &self.field);
+        match &self.condition {
+            Condition::Equals(expected) => equals(value, expected),
+            Condition::NotEquals(expected) => !equals(value, expected),
+            Condition::Matches(re) => re.is_match(&text(value)),
+            Condition::NotMatches(re) => !re.is_match(&text(value)),
+            Condition::Compare(ordering, or_equal, expected) => {
+                let actual = SortKey::from_value(value);
+                let expected = SortKey::from_value(Some(&Value::String(expected.clone())));
+                match (&actual, &expected) {
+                    (SortKey::Number(_), SortKey::Number(_))
+                    | (SortKey::Text(_), SortKey::Text(_)) => {
+                        let cmp = actual.compare(&expected);
+                        cmp == *ordering || (*or_equal && cmp == Ordering::Equal)
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+/// Split on commas, turning `\,` into a literal comma.
+fn split_unescaped(s: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&',') => {
+                chars.next();
+                parts.last_mut().unwrap().push(',');
+            }
+            ',' => parts.push(String::new()),
+            _ => parts.last_mut().unwrap().push(c),
+        }
+    }
+    parts
+}
+
+fn parse_requirement(part: &str) -> Result<Requirement, String> {
+    let start = part
+        .find(['=', '!', '<', '>'])
+        .ok_or_else(|| format!("invalid field selector {part:?} (expected FIELD<op>VALUE)"))?;
+    let field = part[..start].trim().trim_start_matches('.');
+    if field.is_empty() {
+        return Err(format!("missing field name in selector {part:?}"));
+    }
+    let rest = &part[start..];
+    let op = OPERATORS
+        .iter()
+        .find(|op| rest.starts_with(**op))
+        .ok_or_else(|| format!("invalid operator in selector {part:?}"))?;
+    let value = rest[op.len()..].trim().to_string();
+
+    let regex = |value: &str| {
+        Regex::new(value).map_err(|e| format!("invalid regex in selector {part:?}: {e}"))
+    };
+    let condition = match *op {
+        "=" | "==" => Condition::Equals(value),
+        "!=" => Condition::NotEquals(value),
+        "=~" => Condition::Matches(regex(&value)?),
+        "!~" => Condition::NotMatches(regex(&value)?),
+        ">" => Condition::Compare(Ordering::Greater, false, value),
+        ">=" => Condition::Compare(Ordering::Greater, true, value),
+        "<" => Condition::Compare(Ordering::Less, false, value),
+        _ => Condition::Compare(Ordering::Less, true, value),
+    };
+    if matches!(condition, Condition::Compare(_, _, ref v) if v.is_empty()) {
+        return Err(format!("missing value in selector {part:?}"));
+    }
+    Ok(Requirement {
+        field: field.to_string(),
+        condition,
+    })
+}
+
+/// Check `field` against a default-valued resource. Nested paths are accepted
+/// below any field that is not itself an object, since optional nested
+/// objects serialize as null by default.
+fn has_field(template: &Value, field: &str) -> bool {
+    let mut current = template;
+    for key in field.split('.') {
+        match current {
+            Value::Object(map) => match map.get(key) {
+                Some(next) => current = next,
+                None => return false,
+            },
+            _ => return true,
+        }
+    }
+    true
+}
+
+/// The text form of a value; missing and null are the empty string.
+fn text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn equals(value: Option<&Value>, expected: &str) -> bool {
+    let actual = text(value);
+    if actual == expected {
+        return true;
+    }
+    match (
+        SortKey::from_value(value),
+        SortKey::from_value(Some(&Value::String(expected.to_string()))),
+    ) {
+        (SortKey::Number(a), SortKey::Number(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ids(selector: &str, items: &[Value]) -> Vec<String> {
+        let selector: FieldSelector = selector.parse().unwrap();
+        items
+            .iter()
+            .filter(|item| selector.matches(item))
+            .map(|item| item["id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    fn sessions() -> Vec<Value> {
+        vec![
+            json!({"id": "a", "operational_status": "running", "hostname": "agent-01", "share_id": null}),
+            json!({"id": "b", "operational_status": "stopped", "hostname": "agent-02", "share_id": "s1"}),
+            json!({"id": "c", "operational_status": "running", "hostname": "agent-07", "share_id": ""}),
+        ]
+    }
+
+    #[test]
+    fn equality_and_inequality() {
+        assert_eq!(ids("operational_status=running", &sessions()), ["a", "c"]);
+        assert_eq!(ids("operational_status==stopped", &sessions()), ["b"]);
+        assert_eq!(ids("operational_status!=running", &sessions()), ["b"]);
+    }
+
+    #[test]
+    fn empty_value_matches_missing_and_null() {
+        assert_eq!(ids("share_id=", &sessions()), ["a", "c"]);
+        assert_eq!(ids("share_id!=", &sessions()), ["b"]);
+        assert_eq!(ids("nope=", &sessions()), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn regex_match_and_non_match() {
+        assert_eq!(ids("hostname=~agent-0[1-3]", &sessions()), ["a", "b"]);
+        assert_eq!(ids("hostname!~^agent-0[1-3]$", &sessions()), ["c"]);
+    }
+
+    #[test]
+    fn requirements_are_combined_with_and() {
+        assert_eq!(
+            ids(
+                "operational_status=running, hostname=~agent-0[1-3]",
+                &sessions()
+            ),
+            ["a"]
+        );
+    }
+
+    #[test]
+    fn ordering_compares_numbers_bytes_and_datetimes_by_value() {
+        let items = vec![
+            json!({"id": "a", "memory": 4294967296u64, "created": "2024-01-02 00:00:00"}),
+            json!({"id": "b", "memory": 1073741824u64, "created": "2023-06-01 12:00:00"}),
+            json!({"id": "c", "memory": null, "created": null}),
+        ];
+        assert_eq!(ids("memory>2GB", &items), ["a"]);
+        assert_eq!(ids("memory<=1024MB", &items), ["b"]);
+        assert_eq!(ids("memory>=1073741824", &items), ["a", "b"]);
+        assert_eq!(ids("created<2024-01-01 00:00:00", &items), ["b"]);
+        assert_eq!(ids("memory=1GB", &items), ["b"]);
+    }
+
+    #[test]
+    fn nested_fields_and_escaped_commas() {
+        let items = vec![
+            json!({"id": "a", "image": {"friendly_name": "Chrome, Stable"}}),
+            json!({"id": "b", "image": null}),
+        ];
+        assert_eq!(ids(r"image.friendly_name=Chrome\, Stable", &items), ["a"]);
+        assert_eq!(ids(r"image.friendly_name=~^\w{1\,6}\b", &items), ["a"]);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_selectors() {
+        assert!("status".parse::<FieldSelector>().is_err());
+        assert!("=running".parse::<FieldSelector>().is_err());
+        assert!("hostname=~agent-[".parse::<FieldSelector>().is_err());
+        assert!("memory>".parse::<FieldSelector>().is_err());
+        assert!("a=1,".parse::<FieldSelector>().is_err());
+    }
+
+    #[test]
+    fn parser_checks_fields_against_the_resource() {
+        use crate::models::session::Session;
+
+        assert!(FieldSelector::parser::<Session>("operational_status=running").is_ok());
+        assert!(FieldSelector::parser::<Session>("image.friendly_name=Terminal").is_ok());
+        let err = FieldSelector::parser::<Session>("operatonal_status=running").unwrap_err();
+        assert!(err.contains("unknown field \"operatonal_status\""));
+    }
+}
@@ -0,0 +1,180 @@
+//! `--sort-by` for list commands.
+//!
+//! Items are sorted on a field of their serialized form, so any field shown by
+//! `-o json` can be used. Values are compared by type: numbers numerically,
+//! `YYYY-MM-DD HH:MM:SS` datetimes chronologically, byte counts such as `2GB`
+//! by size, and everything else as text. Items without a value sort last.
+
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::filters::parse_memory;
+use crate::output::display::parse_datetime_to_epoch;
+
+/// Comparable form of a field value.
+#[derive(Debug, PartialEq)]
+pub(crate) enum SortKey {
+    Number(f64),
+    Text(String),
+    Missing,
+}
+
+impl SortKey {
+    pub(crate) fn from_value(value: Option<&Value>) -> Self {
+        match value {
+            None | Some(Value::Null) => SortKey::Missing,
+            Some(Value::Number(n)) => n.as_f64().map_or(SortKey::Missing, SortKey::Number),
+            Some(Value::Bool(b)) => SortKey::Number(if *b { 1.0 } else { 0.0 }),
+            Some(Value::String(s)) if s.is_empty() => SortKey::Missing,
+            Some(Value::String(s)) => parse_datetime_to_epoch(s)
+                .map(|epoch| epoch as f64)
+                .or_else(|| s.parse::<f64>().ok())
+                .or_else(|| {
+                    let lower = s.to_lowercase();
+                    (lower.ends_with("gb") || lower.ends_with("mb"))
+                        .then(|| parse_memory(s).ok())
+                        .flatten()
+                        .map(|bytes| bytes as f64)
+                })
+                .map_or_else(|| SortKey::Text(s.clone()), SortKey::Number),
+            Some(other) => SortKey::Text(other.to_string()),
+        }
+    }
+
+    /// Numbers before text; missing values are handled by the caller.
+    pub(crate) fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (SortKey::Number(a), SortKey::Number(b)) => a.total_cmp(b),
+            (SortKey::Text(a), SortKey::Text(b)) => a.cmp(b),
+            (SortKey::Number(_), _) => Ordering::Less,
+            (_, SortKey::Number(_)) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// Look up a dotted field path such as `image.friendly_name` (a leading `.` is allowed).
+pub(crate) fn lookup<'a>(value: &'a Value, field: &str) -> Option<&'a Value> {
+    field
+        .trim_start_matches('.')
+        .split('.')
+        .try_fold(value, |v, key| v.get(key))
+}
+
+/// Sort `items` by `field` of their serialized form. The sort is stable and
+/// items missing the field always come last, even with `reverse`.
+///
+/// Fails if no item has the field at all, which usually means a typo.
+pub fn sort_by_field<T: Serialize>(items: &mut Vec<T>, field: &str, reverse: bool) -> Result<()> {
+    let values = items
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    if !values.is_empty() && values.iter().all(|v| lookup(v, field).is_none()) {
+        anyhow::bail!("unknown sort field {field:?}; use a field name from `-o json` output");
+    }
+
+    let mut keyed: Vec<(SortKey, T)> = values
+        .iter()
+        .map(|v| SortKey::from_value(lookup(v, field)))
+        .zip(items.drain(..))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+        (SortKey::Missing, SortKey::Missing) => Ordering::Equal,
+        (SortKey::Missing, _) => Ordering::Greater,
+        (_, SortKey::Missing) => Ordering::Less,
+        _ if reverse => b.compare(a),
+        _ => a.compare(b),
+    });
+    items.extend(keyed.into_iter().map(|(_, item)| item));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sorted(mut items: Vec<Value>, field: &str, reverse: bool) -> Vec<Value> {
+        sort_by_field(&mut items, field, reverse).unwrap();
+        items
+    }
+
+    fn ids(items: &[Value]) -> Vec<&str> {
+        items.iter().map(|v| v["id"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn sorts_numbers_numerically() {
+        let items = vec![
+            json!({"id": "a", "memory_free": 10_000_000_000u64}),
+            json!({"id": "b", "memory_free": 900}),
+            json!({"id": "c", "memory_free": 5_000_000}),
+        ];
+        assert_eq!(
+            ids(&sorted(items.clone(), "memory_free", false)),
+            ["b", "c", "a"]
+        );
+        assert_eq!(ids(&sorted(items, "memory_free", true)), ["a", "c", "b"]);
+    }
+
+    #[test]
+    fn sorts_datetimes_chronologically() {
+        let items = vec![
+            json!({"id": "a", "keepalive_date": "2024-03-01 00:00:00"}),
+            json!({"id": "b", "keepalive_date": "2023-12-31 23:59:59"}),
+            json!({"id": "c", "keepalive_date": "2024-01-15 08:00:00"}),
+        ];
+        assert_eq!(
+            ids(&sorted(items, ".keepalive_date", false)),
+            ["b", "c", "a"]
+        );
+    }
+
+    #[test]
+    fn sorts_byte_counts_and_numeric_strings_by_value() {
+        let items = vec![
+            json!({"id": "a", "size": "2GB"}),
+            json!({"id": "b", "size": "512MB"}),
+            json!({"id": "c", "size": "10"}),
+        ];
+        assert_eq!(ids(&sorted(items, "size", false)), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn missing_values_sort_last_in_both_directions() {
+        let items = vec![
+            json!({"id": "a", "hostname": null}),
+            json!({"id": "b", "hostname": "beta"}),
+            json!({"id": "c", "hostname": "alpha"}),
+        ];
+        assert_eq!(
+            ids(&sorted(items.clone(), "hostname", false)),
+            ["c", "b", "a"]
+        );
+        assert_eq!(ids(&sorted(items, "hostname", true)), ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn sorts_nested_fields_and_is_stable() {
+        let items = vec![
+            json!({"id": "a", "image": {"name": "x"}}),
+            json!({"id": "b", "image": {"name": "w"}}),
+            json!({"id": "c", "image": {"name": "x"}}),
+        ];
+        assert_eq!(ids(&sorted(items, "image.name", false)), ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn unknown_field_is_an_error() {
+        let mut items = vec![json!({"id": "a"})];
+        let err = sort_by_field(&mut items, "nope", false).unwrap_err();
+        assert!(err.to_string().contains("unknown sort field"));
+
+        let mut empty: Vec<Value> = Vec::new();
+        assert!(sort_by_field(&mut empty, "nope", false).is_ok());
+    }
+}
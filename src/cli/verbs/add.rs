@@ -0,0 +1,20 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args)]
+pub struct AddArgs {
+    #[command(subcommand)]
+    pub resource: AddResource,
+}
+
+#[derive(Subcommand)]
+pub enum AddResource {
+    /// Add a user to a group
+    User {
+        /// User to add (exact ID, ID prefix, or case-insensitive username)
+        user: String,
+
+        /// Group to add the user to (exact ID, ID prefix, or case-insensitive name)
+        #[arg(long)]
+        group: String,
+    },
+}
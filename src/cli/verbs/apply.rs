@@ -0,0 +1,10 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+#[derive(Args)]
+pub struct ApplyArgs {
+    /// Manifest file or directory to apply (repeatable; "-" reads from stdin)
+    #[arg(short, long = "filename", value_name = "PATH", required = true)]
+    pub filenames: Vec<PathBuf>,
+}
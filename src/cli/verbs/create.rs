@@ -67,6 +67,49 @@ pub enum CreateResource {
         image_type: Option<String>,
     },
 
+    /// Create a new deployment zone
+    Zone {
+        /// Zone name
+        #[arg(long)]
+        name: String,
+
+        /// Domain allowed as a request origin
+        #[arg(long)]
+        allow_origin_domain: Option<String>,
+
+        /// Upstream authentication address
+        #[arg(long)]
+        upstream_auth_address: Option<String>,
+
+        /// Strategy for picking an agent within the zone
+        #[arg(long)]
+        load_balancing_strategy: Option<String>,
+
+        /// Fall back to other zones when this zone has no capacity
+        #[arg(long)]
+        search_alternate_zones: Option<bool>,
+
+        /// Prefer static agents over auto-scaled ones
+        #[arg(long)]
+        prioritize_static_agents: Option<bool>,
+
+        /// Proxy session connections through the zone's proxy
+        #[arg(long)]
+        proxy_connections: Option<bool>,
+
+        /// Proxy hostname
+        #[arg(long)]
+        proxy_hostname: Option<String>,
+
+        /// Proxy path
+        #[arg(long)]
+        proxy_path: Option<String>,
+
+        /// Proxy port
+        #[arg(long)]
+        proxy_port: Option<i32>,
+    },
+
     /// Create a new server
     Server {
         /// Human-readable name
@@ -113,4 +156,54 @@ pub enum CreateResource {
         #[arg(long)]
         pool_id: Option<String>,
     },
+
+    /// Create a new user
+    User {
+        /// Username (typically an email address)
+        #[arg(long)]
+        username: String,
+
+        /// Initial password (reads from KASMCTL_USER_PASSWORD env var if not provided)
+        #[arg(long, env = "KASMCTL_USER_PASSWORD", hide_env_values = true)]
+        password: String,
+
+        /// First name
+        #[arg(long)]
+        first_name: Option<String>,
+
+        /// Last name
+        #[arg(long)]
+        last_name: Option<String>,
+
+        /// Organization
+        #[arg(long)]
+        organization: Option<String>,
+
+        /// Phone number
+        #[arg(long)]
+        phone: Option<String>,
+
+        /// Create the user in a locked state
+        #[arg(long, default_value_t = false, action = clap::ArgAction::Set)]
+        locked: bool,
+
+        /// Create the user in a disabled state
+        #[arg(long, default_value_t = false, action = clap::ArgAction::Set)]
+        disabled: bool,
+    },
+
+    /// Create a new group
+    Group {
+        /// Group name
+        #[arg(long)]
+        name: String,
+
+        /// Group description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Group priority (lower values take precedence when settings conflict)
+        #[arg(long)]
+        priority: Option<i32>,
+    },
 }
@@ -1,5 +1,9 @@
+use std::num::NonZeroUsize;
+
 use clap::{Args, Subcommand};
 
+use crate::cli::filters::SessionFilters;
+
 #[derive(Args)]
 pub struct DeleteArgs {
     #[command(subcommand)]
@@ -14,14 +18,44 @@ pub enum DeleteResource {
         /// Session ID to delete
         id: String,
     },
+    /// Delete multiple sessions matching filters
+    #[command(alias = "kasms")]
+    Sessions {
+        #[command(flatten)]
+        filters: Box<SessionFilters>,
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+        /// Number of sessions to process concurrently
+        #[arg(long, value_name = "N", default_value = "1")]
+        parallel: NonZeroUsize,
+    },
     /// Delete an image by ID, ID prefix, or friendly name
     Image {
         /// Image to delete (exact ID, ID prefix, or case-insensitive friendly name)
         id: String,
     },
+    /// Delete a zone by ID, ID prefix, or name
+    Zone {
+        /// Zone to delete (exact ID, ID prefix, or case-insensitive name)
+        id: String,
+    },
     /// Delete a server
     Server {
         /// Server ID to delete
         id: String,
     },
+    /// Delete a user by ID, ID prefix, or username
+    User {
+        /// User to delete (exact ID, ID prefix, or case-insensitive username)
+        id: String,
+        /// Delete the user even if they still have sessions
+        #[arg(long)]
+        force: bool,
+    },
+    /// Delete a group by ID, ID prefix, or name
+    Group {
+        /// Group to delete (exact ID, ID prefix, or case-insensitive name)
+        id: String,
+    },
 }
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use clap::{Args, Subcommand};
 
 use crate::cli::filters::SessionFilters;
@@ -32,10 +34,13 @@ pub enum ExecResource {
     #[command(alias = "kasms")]
     Sessions {
         #[command(flatten)]
-        filters: SessionFilters,
+        filters: Box<SessionFilters>,
         /// Skip confirmation prompt
         #[arg(long, short)]
         yes: bool,
+        /// Number of sessions to process concurrently
+        #[arg(long, value_name = "N", default_value = "1")]
+        parallel: NonZeroUsize,
         /// Working directory for the command
         #[arg(long)]
         workdir: Option<String>,
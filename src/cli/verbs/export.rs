@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// Resource type to export
+    pub resource: ExportResource,
+
+    /// Directory to write manifests into (created if missing)
+    #[arg(short = 'd', long, value_name = "DIR")]
+    pub output_dir: PathBuf,
+
+    /// Include server connection_info, which often holds credentials
+    #[arg(long, default_value_t = false)]
+    pub include_secrets: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportResource {
+    Images,
+    Servers,
+    Agents,
+    Zones,
+    All,
+}
+
+impl ExportResource {
+    /// True when exporting `self` should include resources of type `other`.
+    pub fn includes(self, other: ExportResource) -> bool {
+        self == ExportResource::All || self == other
+    }
+}
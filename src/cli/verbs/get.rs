@@ -1,11 +1,32 @@
+use std::time::Duration;
+
 use clap::{Args, Subcommand};
 
-use crate::cli::filters::{AgentFilters, ImageFilters, ServerFilters, SessionFilters, ZoneFilters};
+use crate::cli::filters::{
+    AgentFilters, GroupFilters, ImageFilters, ServerFilters, SessionFilters, UserFilters,
+    ZoneFilters, parse_interval,
+};
 
 #[derive(Args)]
 pub struct GetArgs {
     #[command(subcommand)]
     pub resource: GetResource,
+
+    /// Keep polling and print changes (list commands only)
+    #[arg(short, long, global = true, default_value_t = false)]
+    pub watch: bool,
+
+    /// Polling interval for --watch (e.g. 2s, 0.5s, 1m)
+    #[arg(long, global = true, default_value = "2s", value_parser = parse_interval)]
+    pub interval: Duration,
+
+    /// Sort list output by a field of the JSON output (e.g. keepalive_date, memory_free)
+    #[arg(long, global = true, value_name = "FIELD")]
+    pub sort_by: Option<String>,
+
+    /// Reverse the --sort-by order
+    #[arg(long, global = true, default_value_t = false, requires = "sort_by")]
+    pub reverse: bool,
 }
 
 #[derive(Subcommand)]
@@ -32,9 +53,9 @@ pub enum GetResource {
         #[command(flatten)]
         filters: ImageFilters,
     },
-    /// Get a specific zone by ID
+    /// Get a specific zone by ID, ID prefix, or name
     Zone {
-        /// Zone ID
+        /// Zone to look up (exact ID, ID prefix, or case-insensitive name)
         id: String,
     },
     /// List all zones
@@ -64,4 +85,29 @@ pub enum GetResource {
         #[command(flatten)]
         filters: ServerFilters,
     },
+    /// Get a specific user by ID, ID prefix, or username
+    User {
+        /// User to look up (exact ID, ID prefix, or case-insensitive username)
+        id: String,
+    },
+    /// List all users
+    Users {
+        #[command(flatten)]
+        filters: UserFilters,
+    },
+    /// Get a specific group by ID, ID prefix, or name
+    Group {
+        /// Group to look up (exact ID, ID prefix, or case-insensitive name)
+        id: String,
+    },
+    /// List all groups
+    Groups {
+        #[command(flatten)]
+        filters: GroupFilters,
+    },
+    /// List the users that are members of a group
+    GroupMembers {
+        /// Group to list (exact ID, ID prefix, or case-insensitive name)
+        group: String,
+    },
 }
@@ -0,0 +1,7 @@
+use clap::Args;
+
+#[derive(Args)]
+pub struct JoinArgs {
+    /// Share ID of the session, as printed by `share session`
+    pub share_id: String,
+}
@@ -0,0 +1,33 @@
+use std::num::NonZeroUsize;
+
+use clap::{Args, Subcommand};
+
+use crate::cli::filters::SessionFilters;
+
+#[derive(Args)]
+pub struct KeepaliveArgs {
+    #[command(subcommand)]
+    pub resource: KeepaliveResource,
+}
+
+#[derive(Subcommand)]
+pub enum KeepaliveResource {
+    /// Keep a session alive, extending its expiration
+    #[command(alias = "kasm")]
+    Session {
+        /// Session ID to keep alive
+        id: String,
+    },
+    /// Keep multiple sessions matching filters alive
+    #[command(alias = "kasms")]
+    Sessions {
+        #[command(flatten)]
+        filters: Box<SessionFilters>,
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+        /// Number of sessions to process concurrently
+        #[arg(long, value_name = "N", default_value = "1")]
+        parallel: NonZeroUsize,
+    },
+}
@@ -1,9 +1,20 @@
+pub mod add;
+pub mod apply;
 pub mod create;
 pub mod delete;
+pub mod diff;
 pub mod exec;
+pub mod export;
 pub mod get;
+pub mod join;
+pub mod keepalive;
 pub mod pause;
+pub mod remove;
 pub mod resume;
+pub mod screenshot;
+pub mod share;
+pub mod stats;
 pub mod stop;
 pub mod top;
+pub mod unshare;
 pub mod update;
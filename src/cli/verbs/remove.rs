@@ -0,0 +1,20 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args)]
+pub struct RemoveArgs {
+    #[command(subcommand)]
+    pub resource: RemoveResource,
+}
+
+#[derive(Subcommand)]
+pub enum RemoveResource {
+    /// Remove a user from a group
+    User {
+        /// User to remove (exact ID, ID prefix, or case-insensitive username)
+        user: String,
+
+        /// Group to remove the user from (exact ID, ID prefix, or case-insensitive name)
+        #[arg(long)]
+        group: String,
+    },
+}
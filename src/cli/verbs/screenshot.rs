@@ -0,0 +1,46 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+
+use crate::cli::filters::SessionFilters;
+
+#[derive(Args)]
+pub struct ScreenshotArgs {
+    #[command(subcommand)]
+    pub resource: ScreenshotResource,
+}
+
+#[derive(Subcommand)]
+pub enum ScreenshotResource {
+    /// Save a screenshot of a session
+    #[command(alias = "kasm")]
+    Session {
+        /// Session ID
+        id: String,
+        /// File to write the image to (default: <KASM_ID>.png or .jpg)
+        #[arg(short, long, value_name = "FILE")]
+        file: Option<PathBuf>,
+        /// Scale the screenshot to this width in pixels
+        #[arg(long, value_name = "PIXELS")]
+        width: Option<u32>,
+    },
+    /// Save a screenshot of every session matching filters, one file per session
+    #[command(alias = "kasms")]
+    Sessions {
+        #[command(flatten)]
+        filters: Box<SessionFilters>,
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+        /// Number of sessions to process concurrently
+        #[arg(long, value_name = "N", default_value = "1")]
+        parallel: NonZeroUsize,
+        /// Directory to write the images into (created if missing)
+        #[arg(short = 'd', long, value_name = "DIR", default_value = ".")]
+        output_dir: PathBuf,
+        /// Scale the screenshots to this width in pixels
+        #[arg(long, value_name = "PIXELS")]
+        width: Option<u32>,
+    },
+}
@@ -0,0 +1,17 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args)]
+pub struct ShareArgs {
+    #[command(subcommand)]
+    pub resource: ShareResource,
+}
+
+#[derive(Subcommand)]
+pub enum ShareResource {
+    /// Share a session and print its share link and viewers
+    #[command(alias = "kasm")]
+    Session {
+        /// Session ID to share
+        id: String,
+    },
+}
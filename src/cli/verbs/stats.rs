@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use clap::{Args, Subcommand};
+
+use crate::cli::filters::parse_interval;
+
+#[derive(Args)]
+pub struct StatsArgs {
+    #[command(subcommand)]
+    pub resource: StatsResource,
+
+    /// Keep polling and print the statistics as they change
+    #[arg(short, long, global = true, default_value_t = false)]
+    pub watch: bool,
+
+    /// Polling interval for --watch (e.g. 2s, 0.5s, 1m)
+    #[arg(long, global = true, default_value = "2s", value_parser = parse_interval)]
+    pub interval: Duration,
+}
+
+#[derive(Subcommand)]
+pub enum StatsResource {
+    /// Show frame and bottleneck statistics of a session
+    #[command(alias = "kasm")]
+    Session {
+        /// Session ID
+        id: String,
+    },
+}
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use clap::{Args, Subcommand};
 
 use crate::cli::filters::SessionFilters;
@@ -20,9 +22,12 @@ pub enum StopResource {
     #[command(alias = "kasms")]
     Sessions {
         #[command(flatten)]
-        filters: SessionFilters,
+        filters: Box<SessionFilters>,
         /// Skip confirmation prompt
         #[arg(long, short)]
         yes: bool,
+        /// Number of sessions to process concurrently
+        #[arg(long, value_name = "N", default_value = "1")]
+        parallel: NonZeroUsize,
     },
 }
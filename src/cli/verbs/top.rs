@@ -1,9 +1,30 @@
+use std::time::Duration;
+
 use clap::Subcommand;
 
+use crate::cli::filters::parse_interval;
+
 #[derive(clap::Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct TopArgs {
     #[command(subcommand)]
     pub command: Option<TopCommand>,
+
+    /// Open a full-screen dashboard that refreshes periodically
+    #[arg(short, long, default_value_t = false)]
+    pub interactive: bool,
+
+    /// Refresh interval for --interactive (e.g. 2s, 0.5s, 1m)
+    #[arg(long, default_value = "2s", value_parser = parse_interval, requires = "interactive")]
+    pub interval: Duration,
+
+    /// Sort the agent table by a field of the JSON output (e.g. memory_free)
+    #[arg(long, global = true, value_name = "FIELD")]
+    pub sort_by: Option<String>,
+
+    /// Reverse the --sort-by order
+    #[arg(long, global = true, default_value_t = false, requires = "sort_by")]
+    pub reverse: bool,
 }
 
 #[derive(Subcommand)]
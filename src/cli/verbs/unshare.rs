@@ -0,0 +1,17 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args)]
+pub struct UnshareArgs {
+    #[command(subcommand)]
+    pub resource: UnshareResource,
+}
+
+#[derive(Subcommand)]
+pub enum UnshareResource {
+    /// Stop sharing a session
+    #[command(alias = "kasm")]
+    Session {
+        /// Session ID to stop sharing
+        id: String,
+    },
+}
@@ -84,6 +84,52 @@ pub enum UpdateResource {
         auto_prune_images: Option<String>,
     },
 
+    /// Update an existing zone by ID, ID prefix, or name
+    Zone {
+        /// Zone to update (exact ID, ID prefix, or case-insensitive name)
+        id: String,
+
+        /// Zone name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Domain allowed as a request origin
+        #[arg(long)]
+        allow_origin_domain: Option<String>,
+
+        /// Upstream authentication address
+        #[arg(long)]
+        upstream_auth_address: Option<String>,
+
+        /// Strategy for picking an agent within the zone
+        #[arg(long)]
+        load_balancing_strategy: Option<String>,
+
+        /// Fall back to other zones when this zone has no capacity
+        #[arg(long)]
+        search_alternate_zones: Option<bool>,
+
+        /// Prefer static agents over auto-scaled ones
+        #[arg(long)]
+        prioritize_static_agents: Option<bool>,
+
+        /// Proxy session connections through the zone's proxy
+        #[arg(long)]
+        proxy_connections: Option<bool>,
+
+        /// Proxy hostname
+        #[arg(long)]
+        proxy_hostname: Option<String>,
+
+        /// Proxy path
+        #[arg(long)]
+        proxy_path: Option<String>,
+
+        /// Proxy port
+        #[arg(long)]
+        proxy_port: Option<i32>,
+    },
+
     /// Update an existing server
     Server {
         /// Server ID to update
@@ -133,4 +179,60 @@ pub enum UpdateResource {
         #[arg(long)]
         pool_id: Option<String>,
     },
+
+    /// Update an existing user by ID, ID prefix, or username
+    User {
+        /// User to update (exact ID, ID prefix, or case-insensitive username)
+        id: String,
+
+        /// Username
+        #[arg(long)]
+        username: Option<String>,
+
+        /// New password
+        #[arg(long)]
+        password: Option<String>,
+
+        /// First name
+        #[arg(long)]
+        first_name: Option<String>,
+
+        /// Last name
+        #[arg(long)]
+        last_name: Option<String>,
+
+        /// Organization
+        #[arg(long)]
+        organization: Option<String>,
+
+        /// Phone number
+        #[arg(long)]
+        phone: Option<String>,
+
+        /// Lock or unlock the user
+        #[arg(long)]
+        locked: Option<bool>,
+
+        /// Disable or enable the user
+        #[arg(long)]
+        disabled: Option<bool>,
+    },
+
+    /// Update an existing group by ID, ID prefix, or name
+    Group {
+        /// Group to update (exact ID, ID prefix, or case-insensitive name)
+        id: String,
+
+        /// Group name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Group description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Group priority (lower values take precedence when settings conflict)
+        #[arg(long)]
+        priority: Option<i32>,
+    },
 }
@@ -114,6 +114,10 @@ pub fn resolve_server_override(
         api_secret,
         insecure_skip_tls_verify: false,
         timeout_seconds: None,
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
     })
 }
 
@@ -152,6 +156,10 @@ mod tests {
                 api_secret: "secret".into(),
                 insecure_skip_tls_verify: false,
                 timeout_seconds: None,
+                retry: None,
+                certificate_authority: None,
+                client_certificate: None,
+                client_key: None,
             },
         }
     }
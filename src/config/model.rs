@@ -34,4 +34,61 @@ pub struct Context {
         skip_serializing_if = "Option::is_none"
     )]
     pub timeout_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+    /// CA certificates to trust instead of the built-in roots: a PEM file path
+    /// or inline PEM data.
+    #[serde(
+        default,
+        rename = "certificate-authority",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub certificate_authority: Option<String>,
+    /// Client certificate chain for mutual TLS: a PEM file path or inline PEM data.
+    #[serde(
+        default,
+        rename = "client-certificate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_certificate: Option<String>,
+    /// Private key for `client-certificate`: a PEM file path or inline PEM data.
+    #[serde(
+        default,
+        rename = "client-key",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_key: Option<String>,
+}
+
+/// Retry policy for transient failures (connection errors, HTTP 429 and 5xx).
+///
+/// Read-only calls are retried automatically; idempotent changes such as
+/// stop or update only when `retry-mutating` is set. Calls that create
+/// something (sessions, images, ...) or run commands are never retried.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct RetryConfig {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry.
+    pub base_delay_ms: u64,
+    /// Upper bound for the delay between retries.
+    pub max_delay_ms: u64,
+    /// Randomize each delay between half and the full value.
+    pub jitter: bool,
+    /// Also retry idempotent changes such as stop or update; creates and exec
+    /// are never retried.
+    pub retry_mutating: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay_ms: 200,
+            max_delay_ms: 5000,
+            jitter: true,
+            retry_mutating: false,
+        }
+    }
 }
@@ -0,0 +1,752 @@
+//! Interactive full-screen dashboard for `kasmctl top --interactive`.
+//!
+//! The dashboard polls the same data as `kasmctl top`, shows a memory and disk
+//! gauge per agent, and lists sessions in a sortable table. The selected
+//! session can be stopped, paused, resumed, or deleted with a single key.
+//!
+//! [`App`] holds all dashboard state and turns key presses into [`Command`]s,
+//! so it can be tested without a terminal. [`run`] owns the terminal and the
+//! refresh loop.
+
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, TableState};
+
+use crate::api::KasmClient;
+use crate::cli::filters::format_utc_minus;
+use crate::models::report::{AgentResourceReport, format_bytes_human};
+use crate::models::session::Session;
+use crate::output::display::{relative_age, short_id};
+
+/// Key help shown in the footer when no message or prompt is pending.
+const HELP: &str =
+    "↑/↓ select  </> sort  i invert  s stop  p pause  r resume  d delete  F5 refresh  q quit";
+
+/// Everything fetched in one refresh.
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    pub sessions: Vec<Session>,
+    pub agents: Vec<AgentResourceReport>,
+    /// Errors reported in the last 24 hours.
+    pub errors: u64,
+}
+
+impl Snapshot {
+    pub fn fetch(client: &KasmClient) -> Result<Self> {
+        let sessions = client.get_kasms().context("failed to list sessions")?;
+        let errors = client
+            .get_report("get_errors", Some(86400), None)
+            .context("failed to get error count")?
+            .as_u64()
+            .unwrap_or(0);
+        let agents = client
+            .get_agent_report()
+            .context("failed to get agent report")?;
+        Ok(Self {
+            sessions,
+            agents,
+            errors,
+        })
+    }
+
+    /// Number of distinct users that own a session.
+    pub fn users(&self) -> u64 {
+        let mut ids: Vec<&str> = self
+            .sessions
+            .iter()
+            .filter_map(|s| s.user_id.as_deref())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.len() as u64
+    }
+}
+
+/// Column the session list is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Created,
+    Status,
+    Image,
+    User,
+    Host,
+}
+
+impl SortKey {
+    const ALL: [SortKey; 5] = [
+        SortKey::Created,
+        SortKey::Status,
+        SortKey::Image,
+        SortKey::User,
+        SortKey::Host,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortKey::Created => "age",
+            SortKey::Status => "status",
+            SortKey::Image => "image",
+            SortKey::User => "user",
+            SortKey::Host => "host",
+        }
+    }
+
+    fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|k| *k == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        let i = Self::ALL.iter().position(|k| *k == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    fn compare(self, a: &Session, b: &Session) -> Ordering {
+        match self {
+            SortKey::Created => a.created_date.cmp(&b.created_date),
+            SortKey::Status => a.operational_status.cmp(&b.operational_status),
+            SortKey::Image => image_name(a).cmp(&image_name(b)),
+            SortKey::User => a.username.cmp(&b.username),
+            SortKey::Host => a.hostname.cmp(&b.hostname),
+        }
+    }
+}
+
+/// Lifecycle action applied to the selected session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionAction {
+    Stop,
+    Pause,
+    Resume,
+    Delete,
+}
+
+impl SessionAction {
+    pub fn verb(self) -> &'static str {
+        match self {
+            SessionAction::Stop => "stop",
+            SessionAction::Pause => "pause",
+            SessionAction::Resume => "resume",
+            SessionAction::Delete => "delete",
+        }
+    }
+
+    pub fn past_tense(self) -> &'static str {
+        match self {
+            SessionAction::Stop => "stopped",
+            SessionAction::Pause => "paused",
+            SessionAction::Resume => "resumed",
+            SessionAction::Delete => "deleted",
+        }
+    }
+
+    /// Call the matching [`KasmClient`] method for `session`.
+    pub fn perform(self, client: &KasmClient, session: &Session) -> Result<()> {
+        let user_id = match &session.user_id {
+            Some(id) => id.clone(),
+            None => client
+                .resolve_user_id(&session.kasm_id)
+                .context("failed to resolve user for session")?,
+        };
+        match self {
+            SessionAction::Stop => client.stop_kasm(&session.kasm_id, &user_id),
+            SessionAction::Pause => client.pause_kasm(&session.kasm_id, &user_id),
+            SessionAction::Resume => client.resume_kasm(&session.kasm_id, &user_id),
+            SessionAction::Delete => client.destroy_kasm(&session.kasm_id, &user_id),
+        }
+    }
+}
+
+/// What the event loop should do after a key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Quit,
+    Refresh,
+    Run(SessionAction, String),
+}
+
+/// Dashboard state: the latest snapshot, sorting, selection, and footer text.
+#[derive(Debug)]
+pub struct App {
+    snapshot: Snapshot,
+    sort: SortKey,
+    descending: bool,
+    table: TableState,
+    /// Action waiting for `y` before it runs.
+    pending: Option<SessionAction>,
+    message: Option<String>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App {
+    /// Start sorted by age, newest session first.
+    pub fn new() -> Self {
+        Self {
+            snapshot: Snapshot::default(),
+            sort: SortKey::Created,
+            descending: true,
+            table: TableState::default(),
+            pending: None,
+            message: None,
+        }
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    pub fn sort(&self) -> (SortKey, bool) {
+        (self.sort, self.descending)
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    /// Replace the snapshot, keeping the same session selected if it still exists.
+    pub fn update(&mut self, snapshot: Snapshot) {
+        let selected = self.selected().map(|s| s.kasm_id.clone());
+        self.snapshot = snapshot;
+        self.sort_sessions(selected.as_deref());
+    }
+
+    pub fn selected(&self) -> Option<&Session> {
+        self.snapshot.sessions.get(self.table.selected()?)
+    }
+
+    fn sort_sessions(&mut self, keep: Option<&str>) {
+        let (key, descending) = (self.sort, self.descending);
+        self.snapshot.sessions.sort_by(|a, b| {
+            let ord = key.compare(a, b).then_with(|| a.kasm_id.cmp(&b.kasm_id));
+            if descending { ord.reverse() } else { ord }
+        });
+        let sessions = &self.snapshot.sessions;
+        let index = keep
+            .and_then(|id| sessions.iter().position(|s| s.kasm_id == id))
+            .or_else(|| {
+                let last = sessions.len().checked_sub(1)?;
+                Some(self.table.selected().unwrap_or(0).min(last))
+            });
+        self.table.select(index);
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        let len = self.snapshot.sessions.len();
+        if len == 0 {
+            return;
+        }
+        let current = self.table.selected().unwrap_or(0);
+        let next = if forward {
+            (current + 1).min(len - 1)
+        } else {
+            current.saturating_sub(1)
+        };
+        self.table.select(Some(next));
+    }
+
+    fn set_sort(&mut self, sort: SortKey, descending: bool) {
+        self.sort = sort;
+        self.descending = descending;
+        let selected = self.selected().map(|s| s.kasm_id.clone());
+        self.sort_sessions(selected.as_deref());
+    }
+
+    /// Handle a key press and return the command for the event loop, if any.
+    pub fn on_key(&mut self, key: KeyEvent) -> Option<Command> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(Command::Quit);
+        }
+
+        if let Some(action) = self.pending.take() {
+            if key.code == KeyCode::Char('y') {
+                return self.run_on_selected(action);
+            }
+            self.set_message(format!("{} cancelled", action.verb()));
+            return None;
+        }
+
+        self.message = None;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+            KeyCode::F(5) => Some(Command::Refresh),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.move_selection(true);
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.move_selection(false);
+                None
+            }
+            KeyCode::Char('>') => {
+                self.set_sort(self.sort.next(), self.descending);
+                None
+            }
+            KeyCode::Char('<') => {
+                self.set_sort(self.sort.previous(), self.descending);
+                None
+            }
+            KeyCode::Char('i') => {
+                self.set_sort(self.sort, !self.descending);
+                None
+            }
+            KeyCode::Char('s') => self.run_on_selected(SessionAction::Stop),
+            KeyCode::Char('p') => self.run_on_selected(SessionAction::Pause),
+            KeyCode::Char('r') => self.run_on_selected(SessionAction::Resume),
+            KeyCode::Char('d') => {
+                let session = self.selected()?;
+                let prompt = format!("Delete session {}? (y/N)", short_id(&session.kasm_id));
+                self.pending = Some(SessionAction::Delete);
+                self.set_message(prompt);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn run_on_selected(&mut self, action: SessionAction) -> Option<Command> {
+        let kasm_id = self.selected()?.kasm_id.clone();
+        Some(Command::Run(action, kasm_id))
+    }
+}
+
+/// Run the dashboard until the user quits, refreshing every `interval`.
+pub fn run(client: &KasmClient, interval: Duration) -> Result<()> {
+    // Fail before taking over the terminal if the server cannot be reached.
+    let mut app = App::new();
+    app.update(Snapshot::fetch(client)?);
+
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut terminal, &mut app, client, interval);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    client: &KasmClient,
+    interval: Duration,
+) -> Result<()> {
+    let mut last_refresh = Instant::now();
+    let mut refreshed_at = format_utc_minus(0);
+    loop {
+        terminal.draw(|frame| draw(frame, app, &refreshed_at, interval))?;
+
+        let timeout = interval.saturating_sub(last_refresh.elapsed());
+        let mut refresh = timeout.is_zero();
+        if !refresh && event::poll(timeout)? {
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            match app.on_key(key) {
+                None => continue,
+                Some(Command::Quit) => return Ok(()),
+                Some(Command::Refresh) => refresh = true,
+                Some(Command::Run(action, kasm_id)) => {
+                    let session = app
+                        .snapshot()
+                        .sessions
+                        .iter()
+                        .find(|s| s.kasm_id == kasm_id);
+                    let outcome = match session {
+                        Some(session) => action.perform(client, session),
+                        None => Err(anyhow::anyhow!("session no longer exists")),
+                    };
+                    match outcome {
+                        Ok(()) => app.set_message(format!(
+                            "Session {} {}.",
+                            short_id(&kasm_id),
+                            action.past_tense()
+                        )),
+                        Err(e) => app.set_message(format!(
+                            "failed to {} session {}: {e:#}",
+                            action.verb(),
+                            short_id(&kasm_id)
+                        )),
+                    }
+                    refresh = true;
+                }
+            }
+        }
+
+        if refresh {
+            match Snapshot::fetch(client) {
+                Ok(snapshot) => {
+                    app.update(snapshot);
+                    refreshed_at = format_utc_minus(0);
+                }
+                Err(e) => app.set_message(format!("refresh failed: {e:#}")),
+            }
+            last_refresh = Instant::now();
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, app: &mut App, refreshed_at: &str, interval: Duration) {
+    let agent_rows = app.snapshot.agents.len().max(1) as u16;
+    let [header, agents, sessions, footer] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Length(agent_rows + 2),
+        Constraint::Min(3),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let snapshot = &app.snapshot;
+    let (sort, descending) = app.sort();
+    let summary = format!(
+        "Sessions: {} running    Users: {} connected    Errors: {} (24h)",
+        snapshot.sessions.len(),
+        snapshot.users(),
+        snapshot.errors
+    );
+    let status = format!(
+        "Sorted by {} {}    Refreshed {refreshed_at} UTC (every {interval:?})",
+        sort.label(),
+        if descending { "↓" } else { "↑" }
+    );
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::styled(summary, Style::new().add_modifier(Modifier::BOLD)),
+            Line::raw(status),
+        ]),
+        header,
+    );
+
+    draw_agents(frame, &snapshot.agents, agents);
+    draw_sessions(frame, app, sessions);
+
+    let footer_text = app.message().unwrap_or(HELP);
+    frame.render_widget(
+        Paragraph::new(footer_text).style(Style::new().add_modifier(Modifier::REVERSED)),
+        footer,
+    );
+}
+
+fn draw_agents(frame: &mut Frame, agents: &[AgentResourceReport], area: Rect) {
+    let block = Block::new().borders(Borders::ALL).title(" Agents ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if agents.is_empty() {
+        frame.render_widget(Paragraph::new("No agents reported."), inner);
+        return;
+    }
+
+    let rows = Layout::vertical(vec![Constraint::Length(1); agents.len()]).split(inner);
+    for (agent, row) in agents.iter().zip(rows.iter()) {
+        let [name, memory, disk] = Layout::horizontal([
+            Constraint::Length(24),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .spacing(1)
+        .areas(*row);
+
+        let health = agent.health.as_deref().unwrap_or_default();
+        frame.render_widget(Paragraph::new(format!("{} {health}", agent.name)), name);
+        frame.render_widget(
+            usage_gauge("mem", agent.memory_used, agent.memory_total),
+            memory,
+        );
+        frame.render_widget(
+            usage_gauge("disk", agent.disk_space_used, agent.disk_space),
+            disk,
+        );
+    }
+}
+
+fn usage_gauge(label: &str, used: Option<u64>, total: Option<u64>) -> Gauge<'static> {
+    let (ratio, text) = match (used, total) {
+        (Some(used), Some(total)) if total > 0 => (
+            (used as f64 / total as f64).clamp(0.0, 1.0),
+            format!(
+                "{label} {}/{}",
+                format_bytes_human(used),
+                format_bytes_human(total)
+            ),
+        ),
+        _ => (0.0, format!("{label} n/a")),
+    };
+    let color = match ratio {
+        r if r >= 0.9 => Color::Red,
+        r if r >= 0.75 => Color::Yellow,
+        _ => Color::Green,
+    };
+    Gauge::default()
+        .gauge_style(Style::new().fg(color))
+        .ratio(ratio)
+        .label(text)
+}
+
+fn draw_sessions(frame: &mut Frame, app: &mut App, area: Rect) {
+    let header = Row::new(["KASM ID", "STATUS", "IMAGE", "USER", "HOST", "AGE"])
+        .style(Style::new().add_modifier(Modifier::BOLD));
+    let rows = app.snapshot.sessions.iter().map(|s| {
+        Row::new([
+            Cell::from(short_id(&s.kasm_id).to_string()),
+            Cell::from(s.operational_status.clone().unwrap_or_default()),
+            Cell::from(image_name(s).unwrap_or_default().to_string()),
+            Cell::from(s.username.clone().unwrap_or_default()),
+            Cell::from(s.hostname.clone().unwrap_or_default()),
+            Cell::from(
+                s.created_date
+                    .as_deref()
+                    .map(relative_age)
+                    .unwrap_or_default(),
+            ),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(Block::new().borders(Borders::ALL).title(" Sessions "))
+    .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(table, area, &mut app.table);
+}
+
+fn image_name(session: &Session) -> Option<&str> {
+    session
+        .image
+        .as_ref()
+        .and_then(|i| i.friendly_name.as_deref().or(i.name.as_deref()))
+        .or(session.image_id.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(id: &str, created: &str, user: &str) -> Session {
+        Session {
+            kasm_id: id.into(),
+            user_id: Some(format!("u-{user}")),
+            username: Some(user.into()),
+            created_date: Some(created.into()),
+            ..Default::default()
+        }
+    }
+
+    fn app_with(sessions: Vec<Session>) -> App {
+        let mut app = App::new();
+        app.update(Snapshot {
+            sessions,
+            ..Default::default()
+        });
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) -> Option<Command> {
+        app.on_key(KeyEvent::from(code))
+    }
+
+    fn ids(app: &App) -> Vec<&str> {
+        app.snapshot()
+            .sessions
+            .iter()
+            .map(|s| s.kasm_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn sessions_start_sorted_newest_first_with_first_selected() {
+        let app = app_with(vec![
+            session("a", "2024-01-01 00:00:00", "zed"),
+            session("b", "2024-03-01 00:00:00", "amy"),
+            session("c", "2024-02-01 00:00:00", "bob"),
+        ]);
+        assert_eq!(ids(&app), ["b", "c", "a"]);
+        assert_eq!(app.selected().unwrap().kasm_id, "b");
+    }
+
+    #[test]
+    fn sort_keys_cycle_and_invert() {
+        let mut app = app_with(vec![
+            session("a", "2024-01-01 00:00:00", "zed"),
+            session("b", "2024-03-01 00:00:00", "amy"),
+        ]);
+        press(&mut app, KeyCode::Char('<'));
+        assert_eq!(app.sort(), (SortKey::Host, true));
+        press(&mut app, KeyCode::Char('>'));
+        press(&mut app, KeyCode::Char('>'));
+        press(&mut app, KeyCode::Char('>'));
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(app.sort(), (SortKey::User, true));
+        assert_eq!(ids(&app), ["a", "b"]);
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.sort(), (SortKey::User, false));
+        assert_eq!(ids(&app), ["b", "a"]);
+    }
+
+    #[test]
+    fn selection_follows_session_across_sort_and_refresh() {
+        let mut app = app_with(vec![
+            session("a", "2024-01-01 00:00:00", "zed"),
+            session("b", "2024-03-01 00:00:00", "amy"),
+            session("c", "2024-02-01 00:00:00", "bob"),
+        ]);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.selected().unwrap().kasm_id, "c");
+
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.selected().unwrap().kasm_id, "c");
+
+        app.update(Snapshot {
+            sessions: vec![
+                session("c", "2024-02-01 00:00:00", "bob"),
+                session("d", "2024-04-01 00:00:00", "dan"),
+            ],
+            ..Default::default()
+        });
+        assert_eq!(app.selected().unwrap().kasm_id, "c");
+    }
+
+    #[test]
+    fn selection_is_clamped_when_selected_session_disappears() {
+        let mut app = app_with(vec![
+            session("a", "2024-01-01 00:00:00", "zed"),
+            session("b", "2024-03-01 00:00:00", "amy"),
+        ]);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.selected().unwrap().kasm_id, "a");
+
+        app.update(Snapshot {
+            sessions: vec![session("b", "2024-03-01 00:00:00", "amy")],
+            ..Default::default()
+        });
+        assert_eq!(app.selected().unwrap().kasm_id, "b");
+
+        app.update(Snapshot::default());
+        assert!(app.selected().is_none());
+        assert_eq!(press(&mut app, KeyCode::Char('s')), None);
+    }
+
+    #[test]
+    fn action_keys_run_on_selected_session() {
+        let mut app = app_with(vec![session("a", "2024-01-01 00:00:00", "zed")]);
+        assert_eq!(
+            press(&mut app, KeyCode::Char('s')),
+            Some(Command::Run(SessionAction::Stop, "a".into()))
+        );
+        assert_eq!(
+            press(&mut app, KeyCode::Char('p')),
+            Some(Command::Run(SessionAction::Pause, "a".into()))
+        );
+        assert_eq!(
+            press(&mut app, KeyCode::Char('r')),
+            Some(Command::Run(SessionAction::Resume, "a".into()))
+        );
+    }
+
+    #[test]
+    fn delete_requires_confirmation() {
+        let mut app = app_with(vec![session("a", "2024-01-01 00:00:00", "zed")]);
+        assert_eq!(press(&mut app, KeyCode::Char('d')), None);
+        assert!(app.message().unwrap().contains("Delete session a?"));
+        assert_eq!(
+            press(&mut app, KeyCode::Char('y')),
+            Some(Command::Run(SessionAction::Delete, "a".into()))
+        );
+
+        press(&mut app, KeyCode::Char('d'));
+        assert_eq!(press(&mut app, KeyCode::Char('q')), None);
+        assert_eq!(app.message(), Some("delete cancelled"));
+        assert_eq!(press(&mut app, KeyCode::Char('q')), Some(Command::Quit));
+    }
+
+    #[test]
+    fn quit_and_refresh_keys() {
+        let mut app = App::new();
+        assert_eq!(press(&mut app, KeyCode::Esc), Some(Command::Quit));
+        assert_eq!(press(&mut app, KeyCode::F(5)), Some(Command::Refresh));
+        assert_eq!(
+            app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Command::Quit)
+        );
+    }
+
+    #[test]
+    fn draw_shows_summary_gauges_and_sessions() {
+        use ratatui::Terminal;
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::new();
+        app.update(Snapshot {
+            sessions: vec![session("abcdef1234", "2024-01-01 00:00:00", "zed")],
+            agents: vec![AgentResourceReport {
+                name: "agent-1".into(),
+                memory_used: Some(3 * 1_073_741_824),
+                memory_total: Some(4 * 1_073_741_824),
+                ..Default::default()
+            }],
+            errors: 7,
+        });
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|frame| {
+                draw(
+                    frame,
+                    &mut app,
+                    "2024-01-01 00:00:00",
+                    Duration::from_secs(2),
+                )
+            })
+            .unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Sessions: 1 running"));
+        assert!(screen.contains("Errors: 7 (24h)"));
+        assert!(screen.contains("mem 3.0GB/4.0GB"));
+        assert!(screen.contains("disk n/a"));
+        assert!(screen.contains("abcdef12"));
+    }
+
+    #[test]
+    fn users_counts_distinct_owners() {
+        let snapshot = Snapshot {
+            sessions: vec![
+                session("a", "2024-01-01 00:00:00", "zed"),
+                session("b", "2024-01-01 00:00:00", "zed"),
+                session("c", "2024-01-01 00:00:00", "amy"),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(snapshot.users(), 2);
+    }
+}
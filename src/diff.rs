@@ -0,0 +1,303 @@
+//! Drift detection between manifests and the live deployment for `kasmctl diff`.
+//!
+//! For every manifest document, the fields set in its spec are compared with
+//! the same fields of the live object. Both sides are rendered as YAML and
+//! compared line by line, so the output reads like a regular unified diff.
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use similar::TextDiff;
+
+use crate::api::KasmClient;
+use crate::manifest::{LiveObjects, Manifest, normalize_config};
+
+/// Placeholder shown instead of secret values.
+const REDACTED: &str = "<redacted>";
+/// Placeholder shown on the manifest side when a secret value differs.
+const REDACTED_CHANGED: &str = "<redacted, changed>";
+
+/// Spec fields that hold secrets and are never printed.
+const SECRET_FIELDS: &[&str] = &["connection_info"];
+
+/// Spec fields that hold run/exec configs and are compared by content.
+const CONFIG_FIELDS: &[&str] = &["run_config", "exec_config"];
+
+/// Comparison of one manifest document against its live object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDiff {
+    pub kind: &'static str,
+    pub name: String,
+    /// Live fields as YAML, or `None` when the object does not exist yet.
+    pub live: Option<String>,
+    /// Desired fields as YAML.
+    pub desired: String,
+}
+
+impl ObjectDiff {
+    /// True when applying the manifest would create or change the object.
+    pub fn has_drift(&self) -> bool {
+        self.live.as_deref() != Some(self.desired.as_str())
+    }
+
+    /// Render a unified diff from the live object to the manifest.
+    /// Returns an empty string when there is no drift.
+    pub fn unified(&self) -> String {
+        if !self.has_drift() {
+            return String::new();
+        }
+        let live = self.live.as_deref().unwrap_or_default();
+        let old_header = match self.live {
+            Some(_) => format!("live/{}/{}", self.kind, self.name),
+            None => "/dev/null".to_string(),
+        };
+        let new_header = format!("manifest/{}/{}", self.kind, self.name);
+        TextDiff::from_lines(live, &self.desired)
+            .unified_diff()
+            .context_radius(3)
+            .header(&old_header, &new_header)
+            .to_string()
+    }
+}
+
+/// Add ANSI colors to a unified diff: removals red, additions green, hunk
+/// headers cyan, file headers bold.
+pub fn colorize(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            let code = if line.starts_with("---") || line.starts_with("+++") {
+                "1"
+            } else if line.starts_with('-') {
+                "31"
+            } else if line.starts_with('+') {
+                "32"
+            } else if line.starts_with("@@") {
+                "36"
+            } else {
+                return format!("{line}\n");
+            };
+            format!("\x1b[{code}m{line}\x1b[0m\n")
+        })
+        .collect()
+}
+
+/// Compares manifests against live objects, fetching each listing at most once.
+pub struct Differ<'a> {
+    client: &'a KasmClient,
+    live: LiveObjects,
+}
+
+impl<'a> Differ<'a> {
+    pub fn new(client: &'a KasmClient) -> Self {
+        Self {
+            client,
+            live: LiveObjects::new(),
+        }
+    }
+
+    pub fn diff(&mut self, manifest: &Manifest) -> Result<ObjectDiff> {
+        let (desired, live) = match manifest {
+            Manifest::Image(spec) => {
+                let images = self.live.images(self.client)?;
+                let live = spec.find(images)?.map(|i| &images[i]);
+                // The image type can only be set on create, so ignore it for existing images.
+                let skip: &[&str] = if live.is_some() {
+                    &["image_id", "image_type"]
+                } else {
+                    &["image_id"]
+                };
+                (to_fields(spec, skip)?, live.map(to_object).transpose()?)
+            }
+            Manifest::Server(spec) => {
+                let servers = self.live.servers(self.client)?;
+                let live = spec.find(servers)?.map(|i| &servers[i]);
+                (
+                    to_fields(spec, &["server_id"])?,
+                    live.map(to_object).transpose()?,
+                )
+            }
+            Manifest::Agent(spec) => {
+                let agents = self.live.agents(self.client)?;
+                let index = spec.find(agents)?;
+                (
+                    to_fields(spec, &["agent_id", "hostname"])?,
+                    Some(to_object(&agents[index])?),
+                )
+            }
+            Manifest::Zone(spec) => {
+                let zones = self.live.zones(self.client)?;
+                let live = spec.find(zones)?.map(|i| &zones[i]);
+                (
+                    to_fields(spec, &["zone_id"])?,
+                    live.map(to_object).transpose()?,
+                )
+            }
+        };
+
+        let (live, desired) = compare_fields(desired, live);
+        Ok(ObjectDiff {
+            kind: manifest.kind(),
+            name: manifest.name().to_string(),
+            live: live.map(|l| render(&l)).transpose()?,
+            desired: render(&desired)?,
+        })
+    }
+}
+
+/// Serialize a spec into its set fields, dropping unset ones and `skip`.
+fn to_fields<T: Serialize>(spec: &T, skip: &[&str]) -> Result<Map<String, Value>> {
+    let mut fields = to_object(spec)?;
+    fields.retain(|key, value| !value.is_null() && !skip.contains(&key.as_str()));
+    Ok(fields)
+}
+
+fn to_object<T: Serialize>(value: &T) -> Result<Map<String, Value>> {
+    match serde_json::to_value(value)? {
+        Value::Object(map) => Ok(map),
+        other => anyhow::bail!("expected an object, got {other}"),
+    }
+}
+
+/// Project the live object onto the desired fields, normalizing configs and
+/// redacting secrets on both sides.
+fn compare_fields(
+    mut desired: Map<String, Value>,
+    live: Option<Map<String, Value>>,
+) -> (Option<Map<String, Value>>, Map<String, Value>) {
+    let live = live.map(|live| {
+        desired
+            .iter_mut()
+            .map(|(key, want)| {
+                let mut have = live.get(key).cloned().unwrap_or(Value::Null);
+                if CONFIG_FIELDS.contains(&key.as_str()) {
+                    have = normalize_config(&have);
+                    *want = normalize_config(want);
+                }
+                if SECRET_FIELDS.contains(&key.as_str()) {
+                    let same = have == *want;
+                    have = Value::String(REDACTED.into());
+                    *want = Value::String(if same { REDACTED } else { REDACTED_CHANGED }.into());
+                }
+                (key.clone(), have)
+            })
+            .collect()
+    });
+
+    if live.is_none() {
+        for (key, want) in desired.iter_mut() {
+            if CONFIG_FIELDS.contains(&key.as_str()) {
+                *want = normalize_config(want);
+            }
+            if SECRET_FIELDS.contains(&key.as_str()) {
+                *want = Value::String(REDACTED.into());
+            }
+        }
+    }
+
+    (live, desired)
+}
+
+fn render(fields: &Map<String, Value>) -> Result<String> {
+    Ok(serde_yaml::to_string(fields)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn object(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("expected object"),
+        }
+    }
+
+    #[test]
+    fn matching_fields_have_no_drift() {
+        let desired = object(json!({"friendly_name": "Terminal", "cores": 2.0}));
+        let live = object(
+            json!({"image_id": "img-1", "friendly_name": "Terminal", "cores": 2.0, "memory": 1}),
+        );
+        let (live, desired) = compare_fields(desired, Some(live));
+        let diff = ObjectDiff {
+            kind: "image",
+            name: "Terminal".into(),
+            live: Some(render(&live.unwrap()).unwrap()),
+            desired: render(&desired).unwrap(),
+        };
+        assert!(!diff.has_drift());
+        assert!(diff.unified().is_empty());
+    }
+
+    #[test]
+    fn changed_field_shows_in_unified_diff() {
+        let desired = object(json!({"friendly_name": "Terminal", "cores": 4.0}));
+        let live = object(json!({"friendly_name": "Terminal", "cores": 2.0}));
+        let (live, desired) = compare_fields(desired, Some(live));
+        let diff = ObjectDiff {
+            kind: "image",
+            name: "Terminal".into(),
+            live: Some(render(&live.unwrap()).unwrap()),
+            desired: render(&desired).unwrap(),
+        };
+        let text = diff.unified();
+        assert!(text.contains("--- live/image/Terminal"), "diff was: {text}");
+        assert!(
+            text.contains("+++ manifest/image/Terminal"),
+            "diff was: {text}"
+        );
+        assert!(text.contains("-cores: 2.0"), "diff was: {text}");
+        assert!(text.contains("+cores: 4.0"), "diff was: {text}");
+        assert!(
+            text.contains(" friendly_name: Terminal"),
+            "diff was: {text}"
+        );
+    }
+
+    #[test]
+    fn missing_object_diffs_from_dev_null() {
+        let desired = object(json!({"friendly_name": "New"}));
+        let (live, desired) = compare_fields(desired, None);
+        let diff = ObjectDiff {
+            kind: "server",
+            name: "New".into(),
+            live: live.map(|l| render(&l).unwrap()),
+            desired: render(&desired).unwrap(),
+        };
+        assert!(diff.has_drift());
+        assert!(diff.unified().contains("--- /dev/null"));
+    }
+
+    #[test]
+    fn config_strings_compare_by_content() {
+        let desired = object(json!({"run_config": {"hostname": "kasm"}}));
+        let live = object(json!({"run_config": "{\"hostname\": \"kasm\"}"}));
+        let (live, desired) = compare_fields(desired, Some(live));
+        assert_eq!(live.unwrap(), desired);
+    }
+
+    #[test]
+    fn secrets_are_redacted_but_still_detected() {
+        let desired = object(json!({"connection_info": "new-secret"}));
+        let live = object(json!({"connection_info": "old-secret"}));
+        let (live, desired) = compare_fields(desired, Some(live));
+        let live = render(&live.unwrap()).unwrap();
+        let desired = render(&desired).unwrap();
+        assert!(!live.contains("secret") && !desired.contains("secret"));
+        assert_ne!(live, desired);
+
+        let same = object(json!({"connection_info": "s3cret"}));
+        let (live, desired) = compare_fields(same.clone(), Some(same));
+        assert_eq!(live.unwrap(), desired);
+    }
+
+    #[test]
+    fn colorize_marks_additions_and_removals() {
+        let colored = colorize("--- a\n+++ b\n@@ -1 +1 @@\n-old\n+new\n same\n");
+        assert!(colored.contains("\x1b[31m-old\x1b[0m"));
+        assert!(colored.contains("\x1b[32m+new\x1b[0m"));
+        assert!(colored.contains("\x1b[36m@@ -1 +1 @@\x1b[0m"));
+        assert!(colored.contains(" same\n"));
+    }
+}
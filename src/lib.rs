@@ -1,7 +1,12 @@
 pub mod api;
+pub mod bulk;
 pub mod cli;
 pub mod config;
 pub mod confirm;
+pub mod dashboard;
+pub mod diff;
+pub mod manifest;
 pub mod models;
 pub mod output;
 pub mod resource;
+pub mod watch;
@@ -1,5 +1,9 @@
-use std::io;
-use std::time::Instant;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use clap::{CommandFactory, Parser};
@@ -8,43 +12,134 @@ use comfy_table::{Table, presets::UTF8_FULL_CONDENSED};
 
 use kasmctl::api::KasmClient;
 use kasmctl::api::agents::UpdateAgentRequest;
+use kasmctl::api::error::ApiError;
+use kasmctl::api::groups::UpdateGroupRequest;
 use kasmctl::api::images::UpdateImageRequest;
 use kasmctl::api::servers::UpdateServerRequest;
+use kasmctl::api::users::UpdateUserRequest;
+use kasmctl::api::zones::{CreateZoneParams, UpdateZoneRequest};
+use kasmctl::bulk::{self, BulkAction, BulkResult, Outcome};
 use kasmctl::cli::config_cmd::ConfigCommand;
+use kasmctl::cli::filters::SessionFilters;
 use kasmctl::cli::filters::parse_memory;
+use kasmctl::cli::sort;
+use kasmctl::cli::verbs::add::AddResource;
+use kasmctl::cli::verbs::apply::ApplyArgs;
 use kasmctl::cli::verbs::create::CreateResource;
 use kasmctl::cli::verbs::delete::DeleteResource;
+use kasmctl::cli::verbs::diff::DiffArgs;
 use kasmctl::cli::verbs::exec::ExecResource;
-use kasmctl::cli::verbs::get::GetResource;
+use kasmctl::cli::verbs::export::{ExportArgs, ExportResource};
+use kasmctl::cli::verbs::get::{GetArgs, GetResource};
+use kasmctl::cli::verbs::join::JoinArgs;
+use kasmctl::cli::verbs::keepalive::KeepaliveResource;
 use kasmctl::cli::verbs::pause::PauseResource;
+use kasmctl::cli::verbs::remove::RemoveResource;
 use kasmctl::cli::verbs::resume::ResumeResource;
+use kasmctl::cli::verbs::screenshot::ScreenshotResource;
+use kasmctl::cli::verbs::share::ShareResource;
+use kasmctl::cli::verbs::stats::{StatsArgs, StatsResource};
 use kasmctl::cli::verbs::stop::StopResource;
-use kasmctl::cli::verbs::top::TopCommand;
+use kasmctl::cli::verbs::top::{TopArgs, TopCommand};
+use kasmctl::cli::verbs::unshare::UnshareResource;
 use kasmctl::cli::verbs::update::UpdateResource;
-use kasmctl::cli::{Cli, Command};
+use kasmctl::cli::{Cli, Command, RetryArgs};
 use kasmctl::config::model::{Context as KasmContext, NamedContext};
 use kasmctl::config::{load_config, save_config};
 use kasmctl::confirm;
+use kasmctl::dashboard::{self, Snapshot};
+use kasmctl::diff::{self, Differ};
+use kasmctl::manifest::{self, AgentSpec, Applier, ImageSpec, Manifest, ServerSpec, ZoneSpec};
 use kasmctl::models::report::{HealthStatus, TopOverview};
+use kasmctl::models::session::{Session, SessionShare};
+use kasmctl::models::stats::SessionStats;
+use kasmctl::output::display::relative_time;
 use kasmctl::output::{self, OutputFormat};
+use kasmctl::resource::Identified;
+use kasmctl::watch;
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Process exit codes, documented in docs/commands.md. Usage errors exit with
+/// 2, as reported by clap.
+mod exit {
+    pub const FAILURE: u8 = 1;
+    pub const NOT_FOUND: u8 = 3;
+    pub const UNAUTHORIZED: u8 = 4;
+    pub const FORBIDDEN: u8 = 5;
+    pub const AMBIGUOUS: u8 = 6;
+    pub const TIMEOUT: u8 = 7;
+    pub const TLS: u8 = 8;
+    /// `diff` found objects that `apply` would change.
+    pub const DRIFT: u8 = 9;
+}
+
+/// Returned by `diff` when it found drift; not an error, so `main` exits with
+/// [`exit::DRIFT`] without printing anything more.
+#[derive(Debug)]
+struct Drift;
+
+impl std::fmt::Display for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("drift found")
+    }
+}
+
+impl std::error::Error for Drift {}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) if e.is::<Drift>() => ExitCode::from(exit::DRIFT),
+        Err(e) => {
+            eprintln!("Error: {e:?}");
+            ExitCode::from(exit_code(&e))
+        }
+    }
+}
+
+/// The exit code for the first classified API error behind `err`.
+fn exit_code(err: &anyhow::Error) -> u8 {
+    match ApiError::find(err) {
+        Some(ApiError::NotFound(_)) => exit::NOT_FOUND,
+        Some(ApiError::Unauthorized(_)) => exit::UNAUTHORIZED,
+        Some(ApiError::Forbidden(_)) => exit::FORBIDDEN,
+        Some(ApiError::Ambiguous(_)) => exit::AMBIGUOUS,
+        Some(ApiError::Timeout(_)) => exit::TIMEOUT,
+        Some(ApiError::Tls(_)) => exit::TLS,
+        _ => exit::FAILURE,
+    }
+}
+
+fn run() -> Result<()> {
+    let mut cli = Cli::parse();
+    if cli.all_fields {
+        cli.output = std::mem::take(&mut cli.output)
+            .with_all_fields()
+            .map_err(|e| anyhow::anyhow!(e))?;
+    }
 
     match cli.command {
-        Command::Config(args) => handle_config(args.command),
+        Command::Config(_) if cli.dry_run => {
+            anyhow::bail!("--dry-run is not supported for config commands")
+        }
+        Command::Config(args) => handle_config(args.command, &cli.retry),
         Command::Completion { shell } => {
             let mut cmd = Cli::command();
             generate(shell, &mut cmd, "kasmctl", &mut io::stdout());
             Ok(())
         }
         cmd => {
-            let ctx = kasmctl::config::resolve_context(
+            let mut ctx = kasmctl::config::resolve_context(
                 cli.server.as_deref(),
                 cli.context.as_deref(),
                 cli.insecure,
             )?;
-            let client = KasmClient::new(&ctx)?;
+            cli.retry.apply(&mut ctx.retry);
+            if cli.certificate_authority.is_some() {
+                ctx.certificate_authority = cli.certificate_authority.clone();
+            }
+            let client = KasmClient::new(&ctx)?
+                .with_dry_run(cli.dry_run)
+                .with_verbosity(cli.verbose);
 
             let context_name = if cli.server.is_some() {
                 "(inline)".to_string()
@@ -58,26 +153,48 @@ fn main() -> Result<()> {
                 "(unknown)".to_string()
             };
 
-            match cmd {
-                Command::Get(args) => handle_get(&client, args.resource, &cli.output),
+            let result = match cmd {
+                Command::Get(args) => handle_get(&client, args, &cli.output),
                 Command::Create(args) => handle_create(&client, args.resource, &cli.output),
-                Command::Delete(args) => handle_delete(&client, args.resource),
-                Command::Stop(args) => handle_stop(&client, args.resource),
-                Command::Pause(args) => handle_pause(&client, args.resource),
-                Command::Resume(args) => handle_resume(&client, args.resource),
+                Command::Delete(args) => handle_delete(&client, args.resource, &cli.output),
+                Command::Stop(args) => handle_stop(&client, args.resource, &cli.output),
+                Command::Pause(args) => handle_pause(&client, args.resource, &cli.output),
+                Command::Resume(args) => handle_resume(&client, args.resource, &cli.output),
+                Command::Screenshot(args) => handle_screenshot(&client, args.resource, &cli.output),
+                Command::Stats(args) => handle_stats(&client, args, &cli.output),
+                Command::Share(args) => handle_share(&client, args.resource, &cli.output),
+                Command::Unshare(args) => handle_unshare(&client, args.resource),
+                Command::Join(args) => handle_join(&client, args, &cli.output),
+                Command::Keepalive(args) => handle_keepalive(&client, args.resource, &cli.output),
                 Command::Update(args) => handle_update(&client, args.resource, &cli.output),
-                Command::Exec(args) => handle_exec(&client, args.resource),
+                Command::Apply(args) => handle_apply(&client, args, &cli.output),
+                Command::Diff(args) => handle_diff(&client, args),
+                Command::Export(args) => handle_export(&client, args),
+                Command::Exec(args) => handle_exec(&client, args.resource, &cli.output),
+                Command::Add(args) => handle_add(&client, args.resource),
+                Command::Remove(args) => handle_remove(&client, args.resource),
                 Command::Health => handle_health(&client, &ctx, &context_name, &cli.output),
-                Command::Top(args) => handle_top(&client, args.command, &cli.output),
+                Command::Top(args) => handle_top(&client, args, &cli.output),
                 Command::Config(_) | Command::Completion { .. } => unreachable!(),
+            };
+            // A skipped request has already been printed; stop without an error.
+            match result {
+                Err(e) if ApiError::is_dry_run(&e) => Ok(()),
+                other => other,
             }
         }
     }
 }
 
-fn handle_get(client: &KasmClient, resource: GetResource, format: &OutputFormat) -> Result<()> {
-    match resource {
+fn handle_get(client: &KasmClient, args: GetArgs, format: &OutputFormat) -> Result<()> {
+    let list = ListOptions {
+        watch: args.watch.then_some(args.interval),
+        sort_by: args.sort_by,
+        reverse: args.reverse,
+    };
+    match args.resource {
         GetResource::Session { id } => {
+            reject_list_options(&list)?;
             let user_id = client
                 .resolve_user_id(&id)
                 .context("failed to resolve user for session")?;
@@ -88,62 +205,156 @@ fn handle_get(client: &KasmClient, resource: GetResource, format: &OutputFormat)
         }
         GetResource::Sessions { filters } => {
             filters.validate().map_err(|e| anyhow::anyhow!(e))?;
-            let mut sessions = client.get_kasms().context("failed to list sessions")?;
-            filters
-                .apply(&mut sessions)
-                .map_err(|e| anyhow::anyhow!(e))?;
-            println!("{}", output::render_list(&sessions, format)?);
+            list_or_watch(&list, format, || {
+                let mut sessions = client.get_kasms().context("failed to list sessions")?;
+                filters
+                    .apply(&mut sessions)
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                Ok(sessions)
+            })?;
         }
         GetResource::Image { id } => {
+            reject_list_options(&list)?;
             let image = client
                 .resolve_image(&id)
                 .context("failed to resolve image")?;
             println!("{}", output::render_one(&image, format)?);
         }
         GetResource::Images { filters } => {
-            let mut images = client.get_images().context("failed to list images")?;
-            filters.apply(&mut images);
-            println!("{}", output::render_list(&images, format)?);
+            list_or_watch(&list, format, || {
+                let mut images = client.get_images().context("failed to list images")?;
+                filters.apply(&mut images);
+                Ok(images)
+            })?;
         }
         GetResource::Zone { id } => {
-            let zones = client.get_zones().context("failed to list zones")?;
-            let zone = zones
-                .into_iter()
-                .find(|z| z.zone_id == id)
-                .ok_or_else(|| anyhow::anyhow!("zone {id:?} not found"))?;
+            reject_list_options(&list)?;
+            let zone = client.resolve_zone(&id).context("failed to resolve zone")?;
             println!("{}", output::render_one(&zone, format)?);
         }
         GetResource::Zones { filters } => {
-            let mut zones = client.get_zones().context("failed to list zones")?;
-            filters.apply(&mut zones);
-            println!("{}", output::render_list(&zones, format)?);
+            list_or_watch(&list, format, || {
+                let mut zones = client.get_zones().context("failed to list zones")?;
+                filters.apply(&mut zones);
+                Ok(zones)
+            })?;
         }
         GetResource::Agent { id } => {
+            reject_list_options(&list)?;
             let agents = client.get_agents().context("failed to list agents")?;
             let agent = agents
                 .into_iter()
                 .find(|a| a.agent_id == id)
-                .ok_or_else(|| anyhow::anyhow!("agent {id:?} not found"))?;
+                .ok_or_else(|| ApiError::NotFound(format!("agent {id:?} not found")))?;
             println!("{}", output::render_one(&agent, format)?);
         }
         GetResource::Agents { filters } => {
-            let mut agents = client.get_agents().context("failed to list agents")?;
-            filters.apply(&mut agents);
-            println!("{}", output::render_list(&agents, format)?);
+            list_or_watch(&list, format, || {
+                let mut agents = client.get_agents().context("failed to list agents")?;
+                filters.apply(&mut agents);
+                Ok(agents)
+            })?;
         }
         GetResource::Server { id } => {
+            reject_list_options(&list)?;
             let servers = client.get_servers().context("failed to list servers")?;
             let server = servers
                 .into_iter()
                 .find(|s| s.server_id == id)
-                .ok_or_else(|| anyhow::anyhow!("server {id:?} not found"))?;
+                .ok_or_else(|| ApiError::NotFound(format!("server {id:?} not found")))?;
             println!("{}", output::render_one(&server, format)?);
         }
         GetResource::Servers { filters } => {
-            let mut servers = client.get_servers().context("failed to list servers")?;
-            filters.apply(&mut servers);
-            println!("{}", output::render_list(&servers, format)?);
+            list_or_watch(&list, format, || {
+                let mut servers = client.get_servers().context("failed to list servers")?;
+                filters.apply(&mut servers);
+                Ok(servers)
+            })?;
+        }
+        GetResource::User { id } => {
+            reject_list_options(&list)?;
+            let user = client.resolve_user(&id).context("failed to resolve user")?;
+            println!("{}", output::render_one(&user, format)?);
         }
+        GetResource::Users { filters } => {
+            list_or_watch(&list, format, || {
+                let mut users = client.get_users().context("failed to list users")?;
+                filters.apply(&mut users);
+                Ok(users)
+            })?;
+        }
+        GetResource::Group { id } => {
+            reject_list_options(&list)?;
+            let group = client
+                .resolve_group(&id)
+                .context("failed to resolve group")?;
+            println!("{}", output::render_one(&group, format)?);
+        }
+        GetResource::Groups { filters } => {
+            list_or_watch(&list, format, || {
+                let mut groups = client.get_groups().context("failed to list groups")?;
+                filters.apply(&mut groups);
+                Ok(groups)
+            })?;
+        }
+        GetResource::GroupMembers { group } => {
+            let group = client
+                .resolve_group(&group)
+                .context("failed to resolve group")?;
+            list_or_watch(&list, format, || {
+                client
+                    .get_group_users(&group.group_id)
+                    .context("failed to list group members")
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Options shared by every `get` list command.
+struct ListOptions {
+    watch: Option<Duration>,
+    sort_by: Option<String>,
+    reverse: bool,
+}
+
+/// Print a list once, or keep polling it when `--watch` was given.
+/// Each fetched list is sorted first when `--sort-by` was given.
+fn list_or_watch<T, F>(list: &ListOptions, format: &OutputFormat, mut fetch: F) -> Result<()>
+where
+    T: Identified + PartialEq,
+    F: FnMut() -> Result<Vec<T>>,
+{
+    let mut fetch_sorted = || {
+        let mut items = fetch()?;
+        if let Some(field) = &list.sort_by {
+            sort::sort_by_field(&mut items, field, list.reverse)?;
+        }
+        Ok(items)
+    };
+    match list.watch {
+        None => {
+            println!("{}", output::render_list(&fetch_sorted()?, format)?);
+            Ok(())
+        }
+        Some(interval) => watch::run(&watch_title(), interval, format, fetch_sorted),
+    }
+}
+
+/// The command line, shown in the header of a `--watch` redraw.
+fn watch_title() -> String {
+    std::iter::once("kasmctl".to_string())
+        .chain(std::env::args().skip(1))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn reject_list_options(list: &ListOptions) -> Result<()> {
+    if list.watch.is_some() {
+        anyhow::bail!("--watch is only supported when listing resources");
+    }
+    if list.sort_by.is_some() {
+        anyhow::bail!("--sort-by is only supported when listing resources");
     }
     Ok(())
 }
@@ -163,7 +374,7 @@ fn handle_create(
                 .context("failed to create session")?;
 
             match format {
-                OutputFormat::Table => {
+                OutputFormat::Table | OutputFormat::Wide => {
                     println!("Session created: {}", resp.kasm_id);
                     if let Some(url) = &resp.kasm_url {
                         println!("URL: {url}");
@@ -172,12 +383,8 @@ fn handle_create(
                         println!("Status: {status}");
                     }
                 }
-                OutputFormat::Json => {
-                    println!("{}", serde_json::to_string_pretty(&resp)?);
-                }
-                OutputFormat::Yaml => {
-                    println!("{}", serde_yaml::to_string(&resp)?);
-                }
+                OutputFormat::Name => println!("session/{}", resp.kasm_id),
+                _ => println!("{}", output::render_value(&resp, format)?),
             }
         }
         CreateResource::Image {
@@ -216,6 +423,35 @@ fn handle_create(
                 .context("failed to create image")?;
             println!("{}", output::render_one(&image, format)?);
         }
+        CreateResource::Zone {
+            name,
+            allow_origin_domain,
+            upstream_auth_address,
+            load_balancing_strategy,
+            search_alternate_zones,
+            prioritize_static_agents,
+            proxy_connections,
+            proxy_hostname,
+            proxy_path,
+            proxy_port,
+        } => {
+            let params = CreateZoneParams {
+                zone_name: name,
+                allow_origin_domain,
+                upstream_auth_address,
+                load_balancing_strategy,
+                search_alternate_zones,
+                prioritize_static_agents,
+                proxy_connections,
+                proxy_hostname,
+                proxy_path,
+                proxy_port,
+            };
+            let zone = client
+                .create_zone(&params)
+                .context("failed to create zone")?;
+            println!("{}", output::render_one(&zone, format)?);
+        }
         CreateResource::Server {
             friendly_name,
             hostname,
@@ -247,11 +483,55 @@ fn handle_create(
                 .context("failed to create server")?;
             println!("{}", output::render_one(&server, format)?);
         }
+        CreateResource::User {
+            username,
+            password,
+            first_name,
+            last_name,
+            organization,
+            phone,
+            locked,
+            disabled,
+        } => {
+            let params = kasmctl::api::users::CreateUserParams {
+                username,
+                password,
+                first_name,
+                last_name,
+                organization,
+                phone,
+                locked,
+                disabled,
+            };
+            let user = client
+                .create_user(&params)
+                .context("failed to create user")?;
+            println!("{}", output::render_one(&user, format)?);
+        }
+        CreateResource::Group {
+            name,
+            description,
+            priority,
+        } => {
+            let params = kasmctl::api::groups::CreateGroupParams {
+                name,
+                description,
+                priority,
+            };
+            let group = client
+                .create_group(&params)
+                .context("failed to create group")?;
+            println!("{}", output::render_one(&group, format)?);
+        }
     }
     Ok(())
 }
 
-fn handle_delete(client: &KasmClient, resource: DeleteResource) -> Result<()> {
+fn handle_delete(
+    client: &KasmClient,
+    resource: DeleteResource,
+    format: &OutputFormat,
+) -> Result<()> {
     match resource {
         DeleteResource::Session { id } => {
             let user_id = client
@@ -262,6 +542,26 @@ fn handle_delete(client: &KasmClient, resource: DeleteResource) -> Result<()> {
                 .context("failed to delete session")?;
             println!("Session {id} deleted.");
         }
+        DeleteResource::Sessions {
+            filters,
+            yes,
+            parallel,
+        } => {
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Delete,
+                format,
+                |s| {
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    client.destroy_kasm(&s.kasm_id, user_id).into()
+                },
+            )?;
+        }
         DeleteResource::Image { id } => {
             let image = client
                 .resolve_image(&id)
@@ -276,12 +576,46 @@ fn handle_delete(client: &KasmClient, resource: DeleteResource) -> Result<()> {
                 .context("failed to delete image")?;
             println!("Image {display_name:?} deleted.");
         }
+        DeleteResource::Zone { id } => {
+            let zone = client.resolve_zone(&id).context("failed to resolve zone")?;
+            let display_name = zone
+                .zone_name
+                .as_deref()
+                .unwrap_or(&zone.zone_id)
+                .to_string();
+            client
+                .delete_zone(&zone.zone_id)
+                .context("failed to delete zone")?;
+            println!("Zone {display_name:?} deleted.");
+        }
         DeleteResource::Server { id } => {
             client
                 .delete_server(&id)
                 .context("failed to delete server")?;
             println!("Server {id} deleted.");
         }
+        DeleteResource::User { id, force } => {
+            let user = client.resolve_user(&id).context("failed to resolve user")?;
+            let display_name = user
+                .username
+                .as_deref()
+                .unwrap_or(&user.user_id)
+                .to_string();
+            client
+                .delete_user(&user.user_id, force)
+                .context("failed to delete user")?;
+            println!("User {display_name:?} deleted.");
+        }
+        DeleteResource::Group { id } => {
+            let group = client
+                .resolve_group(&id)
+                .context("failed to resolve group")?;
+            let display_name = group.name.as_deref().unwrap_or(&group.group_id).to_string();
+            client
+                .delete_group(&group.group_id)
+                .context("failed to delete group")?;
+            println!("Group {display_name:?} deleted.");
+        }
     }
     Ok(())
 }
@@ -359,6 +693,36 @@ fn handle_update(
                 .context("failed to update agent")?;
             println!("{}", output::render_one(&agent, format)?);
         }
+        UpdateResource::Zone {
+            id,
+            name,
+            allow_origin_domain,
+            upstream_auth_address,
+            load_balancing_strategy,
+            search_alternate_zones,
+            prioritize_static_agents,
+            proxy_connections,
+            proxy_hostname,
+            proxy_path,
+            proxy_port,
+        } => {
+            let resolved = client.resolve_zone(&id).context("failed to resolve zone")?;
+            let req = UpdateZoneRequest {
+                zone_id: resolved.zone_id,
+                zone_name: name,
+                allow_origin_domain,
+                upstream_auth_address,
+                load_balancing_strategy,
+                search_alternate_zones,
+                prioritize_static_agents,
+                proxy_connections,
+                proxy_hostname,
+                proxy_path,
+                proxy_port,
+            };
+            let zone = client.update_zone(&req).context("failed to update zone")?;
+            println!("{}", output::render_one(&zone, format)?);
+        }
         UpdateResource::Server {
             id,
             friendly_name,
@@ -392,11 +756,258 @@ fn handle_update(
                 .context("failed to update server")?;
             println!("{}", output::render_one(&server, format)?);
         }
+        UpdateResource::User {
+            id,
+            username,
+            password,
+            first_name,
+            last_name,
+            organization,
+            phone,
+            locked,
+            disabled,
+        } => {
+            let resolved = client.resolve_user(&id).context("failed to resolve user")?;
+            let req = UpdateUserRequest {
+                user_id: resolved.user_id,
+                username,
+                password,
+                first_name,
+                last_name,
+                organization,
+                phone,
+                locked,
+                disabled,
+            };
+            let user = client.update_user(&req).context("failed to update user")?;
+            println!("{}", output::render_one(&user, format)?);
+        }
+        UpdateResource::Group {
+            id,
+            name,
+            description,
+            priority,
+        } => {
+            let resolved = client
+                .resolve_group(&id)
+                .context("failed to resolve group")?;
+            let req = UpdateGroupRequest {
+                group_id: resolved.group_id,
+                name,
+                description,
+                priority,
+            };
+            let group = client
+                .update_group(&req)
+                .context("failed to update group")?;
+            println!("{}", output::render_one(&group, format)?);
+        }
+    }
+    Ok(())
+}
+
+fn handle_apply(client: &KasmClient, args: ApplyArgs, format: &OutputFormat) -> Result<()> {
+    let mut manifests = Vec::new();
+    for path in &args.filenames {
+        manifests.extend(manifest::load_manifests(path)?);
+    }
+
+    let total = manifests.len();
+    let mut applier = Applier::new(client);
+    let mut results = Vec::new();
+    let mut failed = 0usize;
+
+    for m in &manifests {
+        match applier.apply(m) {
+            Ok(result) => {
+                if format.is_table() {
+                    println!("{result}");
+                }
+                results.push(result);
+            }
+            Err(e) if ApiError::is_dry_run(&e) => {
+                eprintln!("{}/{} (dry run)", m.kind(), m.name());
+            }
+            Err(e) => {
+                eprintln!("error: {}/{}: {e:#}", m.kind(), m.name());
+                failed += 1;
+            }
+        }
+    }
+
+    if !format.is_table() {
+        println!("{}", output::render_list(&results, format)?);
+    }
+
+    if failed > 0 {
+        anyhow::bail!("failed to apply {failed} of {total} objects");
+    }
+    Ok(())
+}
+
+/// Print a unified diff for every manifest and exit with [`exit::DRIFT`] when
+/// any object has drifted, so CI jobs can alert on out-of-band changes.
+fn handle_diff(client: &KasmClient, args: DiffArgs) -> Result<()> {
+    let mut manifests = Vec::new();
+    for path in &args.filenames {
+        manifests.extend(manifest::load_manifests(path)?);
+    }
+
+    let color =
+        io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty());
+    let mut differ = Differ::new(client);
+    let mut drifted = 0usize;
+    let mut failed = 0usize;
+
+    for m in &manifests {
+        match differ.diff(m) {
+            Ok(object) if object.has_drift() => {
+                drifted += 1;
+                let text = object.unified();
+                if color {
+                    print!("{}", diff::colorize(&text));
+                } else {
+                    print!("{text}");
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("error: {}/{}: {e:#}", m.kind(), m.name());
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("failed to diff {failed} of {} objects", manifests.len());
+    }
+    if drifted > 0 {
+        return Err(Drift.into());
+    }
+    Ok(())
+}
+
+fn handle_export(client: &KasmClient, args: ExportArgs) -> Result<()> {
+    let mut manifests = Vec::new();
+
+    if args.resource.includes(ExportResource::Images) {
+        for image in client.get_images().context("failed to list images")? {
+            match ImageSpec::from_live(&image) {
+                Some(spec) => manifests.push(Manifest::Image(spec)),
+                None => eprintln!(
+                    "skipping image {}: missing name or friendly name",
+                    image.image_id
+                ),
+            }
+        }
+    }
+    if args.resource.includes(ExportResource::Servers) {
+        for server in client.get_servers().context("failed to list servers")? {
+            match ServerSpec::from_live(&server, args.include_secrets) {
+                Some(spec) => manifests.push(Manifest::Server(spec)),
+                None => eprintln!(
+                    "skipping server {}: missing friendly name",
+                    server.server_id
+                ),
+            }
+        }
+    }
+    if args.resource.includes(ExportResource::Agents) {
+        for agent in client.get_agents().context("failed to list agents")? {
+            manifests.push(Manifest::Agent(AgentSpec::from_live(&agent)));
+        }
+    }
+    if args.resource.includes(ExportResource::Zones) {
+        for zone in client.get_zones().context("failed to list zones")? {
+            match ZoneSpec::from_live(&zone) {
+                Some(spec) => manifests.push(Manifest::Zone(spec)),
+                None => eprintln!("skipping zone {}: missing zone name", zone.zone_id),
+            }
+        }
+    }
+
+    fs::create_dir_all(&args.output_dir)
+        .with_context(|| format!("failed to create {}", args.output_dir.display()))?;
+    let files = manifest::export_files(&manifests)?;
+    for (file_name, yaml) in &files {
+        let path = args.output_dir.join(file_name);
+        fs::write(&path, yaml).with_context(|| format!("failed to write {}", path.display()))?;
+        println!("{}", path.display());
     }
+    eprintln!(
+        "Exported {} objects to {}.",
+        files.len(),
+        args.output_dir.display()
+    );
     Ok(())
 }
 
-fn handle_stop(client: &KasmClient, resource: StopResource) -> Result<()> {
+/// List the sessions matching `filters`, confirm, and run `op` on each of
+/// them. Table output reports progress as it goes; other formats print a
+/// [`BulkResult`] document at the end.
+fn run_bulk_sessions<F>(
+    client: &KasmClient,
+    filters: &SessionFilters,
+    yes: bool,
+    parallel: NonZeroUsize,
+    action: BulkAction,
+    format: &OutputFormat,
+    op: F,
+) -> Result<()>
+where
+    F: Fn(&Session) -> Outcome + Sync,
+{
+    filters.validate().map_err(|e| anyhow::anyhow!(e))?;
+    // Reject unsupported output formats before any session is touched.
+    let empty = if format.is_table() {
+        None
+    } else {
+        Some(BulkResult::new(action).render(format)?)
+    };
+    let mut sessions = client.get_kasms().context("failed to list sessions")?;
+    filters
+        .apply(&mut sessions)
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    if sessions.is_empty() {
+        eprintln!("No sessions match the given filters.");
+        if let Some(empty) = empty {
+            println!("{empty}");
+        }
+        return Ok(());
+    }
+
+    let msg = if filters.is_empty() {
+        format!("{} ALL {} sessions?", action.prompt(), sessions.len())
+    } else {
+        format!("{} {} matching sessions?", action.prompt(), sessions.len())
+    };
+    let dry_run = client.is_dry_run();
+    if !confirm::confirm(&msg, yes || dry_run) {
+        eprintln!("Aborted.");
+        return Ok(());
+    }
+
+    let result = bulk::run(
+        action,
+        &sessions,
+        parallel,
+        format.is_table(),
+        |s| s.kasm_id.as_str(),
+        op,
+    );
+
+    if !format.is_table() {
+        println!("{}", result.render(format)?);
+    } else if dry_run {
+        eprintln!("{}", result.dry_run_summary());
+    } else {
+        eprintln!("{}", result.summary());
+    }
+    result.check()
+}
+
+fn handle_stop(client: &KasmClient, resource: StopResource, format: &OutputFormat) -> Result<()> {
     match resource {
         StopResource::Session { id } => {
             let user_id = client
@@ -407,75 +1018,35 @@ fn handle_stop(client: &KasmClient, resource: StopResource) -> Result<()> {
                 .context("failed to stop session")?;
             println!("Session {id} stopped.");
         }
-        StopResource::Sessions { filters, yes } => {
-            filters.validate().map_err(|e| anyhow::anyhow!(e))?;
-            let mut sessions = client.get_kasms().context("failed to list sessions")?;
-            filters
-                .apply(&mut sessions)
-                .map_err(|e| anyhow::anyhow!(e))?;
-
-            if sessions.is_empty() {
-                eprintln!("No sessions match the given filters.");
-                return Ok(());
-            }
-
-            let msg = if filters.is_empty() {
-                format!("Stop ALL {} sessions?", sessions.len())
-            } else {
-                format!("Stop {} matching sessions?", sessions.len())
-            };
-            if !confirm::confirm(&msg, yes) {
-                eprintln!("Aborted.");
-                return Ok(());
-            }
-
-            let total = sessions.len();
-            let mut failed = 0usize;
-            let mut skipped = 0usize;
-            for s in &sessions {
-                let op_status = s.operational_status.as_deref().unwrap_or("");
-                if op_status.eq_ignore_ascii_case("stopped") {
-                    eprintln!("  {} skipped ({op_status})", s.kasm_id);
-                    skipped += 1;
-                    continue;
-                }
-                let user_id = match s.user_id.as_deref() {
-                    Some(uid) => uid,
-                    None => {
-                        eprintln!("  {} skipped (no user_id)", s.kasm_id);
-                        skipped += 1;
-                        continue;
-                    }
-                };
-                match client.stop_kasm(&s.kasm_id, user_id) {
-                    Ok(()) => eprintln!("  {} ok", s.kasm_id),
-                    Err(e) => {
-                        eprintln!("  {} FAILED: {e}", s.kasm_id);
-                        failed += 1;
+        StopResource::Sessions {
+            filters,
+            yes,
+            parallel,
+        } => {
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Stop,
+                format,
+                |s| {
+                    let op_status = s.operational_status.as_deref().unwrap_or("");
+                    if op_status.eq_ignore_ascii_case("stopped") {
+                        return Outcome::Skipped(op_status.to_string());
                     }
-                }
-            }
-
-            let attempted = total - skipped;
-            eprintln!(
-                "Stopped {}/{} sessions.{}",
-                attempted - failed,
-                attempted,
-                if skipped > 0 {
-                    format!(" ({skipped} skipped)")
-                } else {
-                    String::new()
-                }
-            );
-            if failed > 0 {
-                anyhow::bail!("{failed} session(s) failed to stop");
-            }
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    client.stop_kasm(&s.kasm_id, user_id).into()
+                },
+            )?;
         }
     }
     Ok(())
 }
 
-fn handle_pause(client: &KasmClient, resource: PauseResource) -> Result<()> {
+fn handle_pause(client: &KasmClient, resource: PauseResource, format: &OutputFormat) -> Result<()> {
     match resource {
         PauseResource::Session { id } => {
             let user_id = client
@@ -486,77 +1057,41 @@ fn handle_pause(client: &KasmClient, resource: PauseResource) -> Result<()> {
                 .context("failed to pause session")?;
             println!("Session {id} paused.");
         }
-        PauseResource::Sessions { filters, yes } => {
-            filters.validate().map_err(|e| anyhow::anyhow!(e))?;
-            let mut sessions = client.get_kasms().context("failed to list sessions")?;
-            filters
-                .apply(&mut sessions)
-                .map_err(|e| anyhow::anyhow!(e))?;
-
-            if sessions.is_empty() {
-                eprintln!("No sessions match the given filters.");
-                return Ok(());
-            }
-
-            let msg = if filters.is_empty() {
-                format!("Pause ALL {} sessions?", sessions.len())
-            } else {
-                format!("Pause {} matching sessions?", sessions.len())
-            };
-            if !confirm::confirm(&msg, yes) {
-                eprintln!("Aborted.");
-                return Ok(());
-            }
-
-            let total = sessions.len();
-            let mut failed = 0usize;
-            let mut skipped = 0usize;
-            for s in &sessions {
-                let op_status = s.operational_status.as_deref().unwrap_or("");
-                if op_status.eq_ignore_ascii_case("stopped")
-                    || op_status.eq_ignore_ascii_case("paused")
-                {
-                    eprintln!("  {} skipped ({op_status})", s.kasm_id);
-                    skipped += 1;
-                    continue;
-                }
-                let user_id = match s.user_id.as_deref() {
-                    Some(uid) => uid,
-                    None => {
-                        eprintln!("  {} skipped (no user_id)", s.kasm_id);
-                        skipped += 1;
-                        continue;
-                    }
-                };
-                match client.pause_kasm(&s.kasm_id, user_id) {
-                    Ok(()) => eprintln!("  {} ok", s.kasm_id),
-                    Err(e) => {
-                        eprintln!("  {} FAILED: {e}", s.kasm_id);
-                        failed += 1;
+        PauseResource::Sessions {
+            filters,
+            yes,
+            parallel,
+        } => {
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Pause,
+                format,
+                |s| {
+                    let op_status = s.operational_status.as_deref().unwrap_or("");
+                    if op_status.eq_ignore_ascii_case("stopped")
+                        || op_status.eq_ignore_ascii_case("paused")
+                    {
+                        return Outcome::Skipped(op_status.to_string());
                     }
-                }
-            }
-
-            let attempted = total - skipped;
-            eprintln!(
-                "Paused {}/{} sessions.{}",
-                attempted - failed,
-                attempted,
-                if skipped > 0 {
-                    format!(" ({skipped} skipped)")
-                } else {
-                    String::new()
-                }
-            );
-            if failed > 0 {
-                anyhow::bail!("{failed} session(s) failed to pause");
-            }
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    client.pause_kasm(&s.kasm_id, user_id).into()
+                },
+            )?;
         }
     }
     Ok(())
 }
 
-fn handle_resume(client: &KasmClient, resource: ResumeResource) -> Result<()> {
+fn handle_resume(
+    client: &KasmClient,
+    resource: ResumeResource,
+    format: &OutputFormat,
+) -> Result<()> {
     match resource {
         ResumeResource::Session { id } => {
             let user_id = client
@@ -567,75 +1102,87 @@ fn handle_resume(client: &KasmClient, resource: ResumeResource) -> Result<()> {
                 .context("failed to resume session")?;
             println!("Session {id} resumed.");
         }
-        ResumeResource::Sessions { filters, yes } => {
-            filters.validate().map_err(|e| anyhow::anyhow!(e))?;
-            let mut sessions = client.get_kasms().context("failed to list sessions")?;
-            filters
-                .apply(&mut sessions)
-                .map_err(|e| anyhow::anyhow!(e))?;
-
-            if sessions.is_empty() {
-                eprintln!("No sessions match the given filters.");
-                return Ok(());
-            }
-
-            let msg = if filters.is_empty() {
-                format!("Resume ALL {} sessions?", sessions.len())
-            } else {
-                format!("Resume {} matching sessions?", sessions.len())
-            };
-            if !confirm::confirm(&msg, yes) {
-                eprintln!("Aborted.");
-                return Ok(());
-            }
-
-            let total = sessions.len();
-            let mut failed = 0usize;
-            let mut skipped = 0usize;
-            for s in &sessions {
-                let op_status = s.operational_status.as_deref().unwrap_or("");
-                if op_status.eq_ignore_ascii_case("running") {
-                    eprintln!("  {} skipped ({op_status})", s.kasm_id);
-                    skipped += 1;
-                    continue;
-                }
-                let user_id = match s.user_id.as_deref() {
-                    Some(uid) => uid,
-                    None => {
-                        eprintln!("  {} skipped (no user_id)", s.kasm_id);
-                        skipped += 1;
-                        continue;
-                    }
-                };
-                match client.resume_kasm(&s.kasm_id, user_id) {
-                    Ok(()) => eprintln!("  {} ok", s.kasm_id),
-                    Err(e) => {
-                        eprintln!("  {} FAILED: {e}", s.kasm_id);
-                        failed += 1;
+        ResumeResource::Sessions {
+            filters,
+            yes,
+            parallel,
+        } => {
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Resume,
+                format,
+                |s| {
+                    let op_status = s.operational_status.as_deref().unwrap_or("");
+                    if op_status.eq_ignore_ascii_case("running") {
+                        return Outcome::Skipped(op_status.to_string());
                     }
-                }
-            }
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    client.resume_kasm(&s.kasm_id, user_id).into()
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
 
-            let attempted = total - skipped;
-            eprintln!(
-                "Resumed {}/{} sessions.{}",
-                attempted - failed,
-                attempted,
-                if skipped > 0 {
-                    format!(" ({skipped} skipped)")
-                } else {
-                    String::new()
-                }
-            );
-            if failed > 0 {
-                anyhow::bail!("{failed} session(s) failed to resume");
-            }
+fn handle_keepalive(
+    client: &KasmClient,
+    resource: KeepaliveResource,
+    format: &OutputFormat,
+) -> Result<()> {
+    match resource {
+        KeepaliveResource::Session { id } => {
+            let user_id = client
+                .resolve_user_id(&id)
+                .context("failed to resolve user for session")?;
+            let expiry = keepalive_session(client, &id, &user_id)?;
+            println!("Session {id} kept alive, {expiry}.");
+        }
+        KeepaliveResource::Sessions {
+            filters,
+            yes,
+            parallel,
+        } => {
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Keepalive,
+                format,
+                |s| {
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    keepalive_session(client, &s.kasm_id, user_id).into()
+                },
+            )?;
         }
     }
     Ok(())
 }
 
-fn handle_exec(client: &KasmClient, resource: ExecResource) -> Result<()> {
+/// Keep a session alive and describe its new expiration, e.g.
+/// `expires 2026-10-17 18:00:00 (in 4h)`.
+fn keepalive_session(client: &KasmClient, kasm_id: &str, user_id: &str) -> Result<String> {
+    client
+        .keepalive(kasm_id, user_id)
+        .context("failed to keep session alive")?;
+    let session = client
+        .get_kasm_status(kasm_id, user_id)
+        .context("failed to get session")?;
+    Ok(match session.expiration_date.as_deref() {
+        Some(date) => format!("expires {date} ({})", relative_time(date)),
+        None => "expiration unknown".to_string(),
+    })
+}
+
+fn handle_exec(client: &KasmClient, resource: ExecResource, format: &OutputFormat) -> Result<()> {
     match resource {
         ExecResource::Session {
             id,
@@ -663,75 +1210,260 @@ fn handle_exec(client: &KasmClient, resource: ExecResource) -> Result<()> {
         ExecResource::Sessions {
             filters,
             yes,
+            parallel,
             workdir,
             privileged,
             exec_user,
             cmd,
         } => {
-            filters.validate().map_err(|e| anyhow::anyhow!(e))?;
-            let mut sessions = client.get_kasms().context("failed to list sessions")?;
-            filters
-                .apply(&mut sessions)
-                .map_err(|e| anyhow::anyhow!(e))?;
+            let cmd_str = cmd.join(" ");
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Exec,
+                format,
+                |s| {
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    client
+                        .exec_command_kasm(
+                            &s.kasm_id,
+                            user_id,
+                            &cmd_str,
+                            workdir.as_deref(),
+                            privileged,
+                            exec_user.as_deref(),
+                        )
+                        .into()
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
 
-            if sessions.is_empty() {
-                eprintln!("No sessions match the given filters.");
+fn handle_screenshot(
+    client: &KasmClient,
+    resource: ScreenshotResource,
+    format: &OutputFormat,
+) -> Result<()> {
+    match resource {
+        ScreenshotResource::Session { id, file, width } => {
+            let user_id = client
+                .resolve_user_id(&id)
+                .context("failed to resolve user for session")?;
+            if client.is_dry_run() {
+                let path = file.unwrap_or_else(|| PathBuf::from(format!("{id}.{{png,jpg}}")));
+                println!(
+                    "Would save screenshot of session {id} to {}.",
+                    path.display()
+                );
                 return Ok(());
             }
+            let screenshot = client
+                .get_kasm_screenshot(&id, &user_id, width)
+                .context("failed to capture screenshot")?;
+            let path =
+                file.unwrap_or_else(|| PathBuf::from(format!("{id}.{}", screenshot.extension())));
+            fs::write(&path, &screenshot.data)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            println!("Screenshot of session {id} saved to {}.", path.display());
+        }
+        ScreenshotResource::Sessions {
+            filters,
+            yes,
+            parallel,
+            output_dir,
+            width,
+        } => {
+            if !client.is_dry_run() {
+                fs::create_dir_all(&output_dir)
+                    .with_context(|| format!("failed to create {}", output_dir.display()))?;
+            }
+            run_bulk_sessions(
+                client,
+                &filters,
+                yes,
+                parallel,
+                BulkAction::Screenshot,
+                format,
+                |s| {
+                    let op_status = s.operational_status.as_deref().unwrap_or("");
+                    if op_status.eq_ignore_ascii_case("stopped")
+                        || op_status.eq_ignore_ascii_case("paused")
+                    {
+                        return Outcome::Skipped(op_status.to_string());
+                    }
+                    let Some(user_id) = s.user_id.as_deref() else {
+                        return Outcome::Skipped("no user_id".into());
+                    };
+                    save_screenshot(client, &s.kasm_id, user_id, width, &output_dir).into()
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
 
-            let msg = if filters.is_empty() {
-                format!("Execute command on ALL {} sessions?", sessions.len())
-            } else {
-                format!("Execute command on {} matching sessions?", sessions.len())
+/// Save a screenshot of one session as `<dir>/<kasm_id>.<ext>`. Under
+/// `--dry-run` only the file that would be written is printed.
+fn save_screenshot(
+    client: &KasmClient,
+    kasm_id: &str,
+    user_id: &str,
+    width: Option<u32>,
+    dir: &Path,
+) -> Result<()> {
+    if client.is_dry_run() {
+        let path = dir.join(format!("{kasm_id}.{{png,jpg}}"));
+        println!(
+            "Would save screenshot of session {kasm_id} to {}.",
+            path.display()
+        );
+        return Err(ApiError::DryRun("public/get_kasm_screenshot".into()).into());
+    }
+    let screenshot = client.get_kasm_screenshot(kasm_id, user_id, width)?;
+    let path = dir.join(format!("{kasm_id}.{}", screenshot.extension()));
+    fs::write(&path, &screenshot.data)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+fn handle_stats(client: &KasmClient, args: StatsArgs, format: &OutputFormat) -> Result<()> {
+    match args.resource {
+        StatsResource::Session { id } => {
+            let user_id = client
+                .resolve_user_id(&id)
+                .context("failed to resolve user for session")?;
+            let fetch = || -> Result<SessionStats> {
+                Ok(SessionStats {
+                    bottleneck: client
+                        .get_kasm_bottleneck_stats(&id, &user_id)
+                        .context("failed to get bottleneck stats")?,
+                    frame: client
+                        .get_kasm_frame_stats(&id, &user_id)
+                        .context("failed to get frame stats")?,
+                    kasm_id: id.clone(),
+                })
             };
-            if !confirm::confirm(&msg, yes) {
-                eprintln!("Aborted.");
-                return Ok(());
+            if args.watch {
+                watch::run(&watch_title(), args.interval, format, || Ok(vec![fetch()?]))?;
+            } else {
+                println!("{}", output::render_one(&fetch()?, format)?);
             }
+        }
+    }
+    Ok(())
+}
 
-            let cmd_str = cmd.join(" ");
-            let total = sessions.len();
-            let mut failed = 0usize;
-            let mut skipped = 0usize;
-            for s in &sessions {
-                let user_id = match &s.user_id {
-                    Some(uid) => uid,
-                    None => {
-                        eprintln!("  {} skipped (no user_id)", s.kasm_id);
-                        skipped += 1;
-                        continue;
-                    }
-                };
-                match client.exec_command_kasm(
-                    &s.kasm_id,
-                    user_id,
-                    &cmd_str,
-                    workdir.as_deref(),
-                    privileged,
-                    exec_user.as_deref(),
-                ) {
-                    Ok(()) => eprintln!("  {} ok", s.kasm_id),
-                    Err(e) => {
-                        eprintln!("  {} FAILED: {e}", s.kasm_id);
-                        failed += 1;
-                    }
-                }
+fn handle_share(client: &KasmClient, resource: ShareResource, format: &OutputFormat) -> Result<()> {
+    match resource {
+        ShareResource::Session { id } => {
+            let user_id = client
+                .resolve_user_id(&id)
+                .context("failed to resolve user for session")?;
+            let session = client
+                .get_kasm_status(&id, &user_id)
+                .context("failed to get session")?;
+            // Sharing an already shared session keeps its link and only reports viewers.
+            let share_id = match session.share_id.filter(|s| !s.is_empty()) {
+                Some(share_id) => share_id,
+                None => client
+                    .create_kasm_share_id(&id, &user_id)
+                    .context("failed to share session")?,
+            };
+            let viewers = client
+                .get_kasm_viewers(&id, &user_id)
+                .context("failed to list session viewers")?;
+            let share = SessionShare {
+                share_url: client.share_url(&share_id),
+                share_id,
+                kasm_id: id,
+                viewers,
+            };
+            println!("{}", output::render_one(&share, format)?);
+        }
+    }
+    Ok(())
+}
+
+fn handle_unshare(client: &KasmClient, resource: UnshareResource) -> Result<()> {
+    match resource {
+        UnshareResource::Session { id } => {
+            let user_id = client
+                .resolve_user_id(&id)
+                .context("failed to resolve user for session")?;
+            client
+                .delete_kasm_share_id(&id, &user_id)
+                .context("failed to unshare session")?;
+            println!("Session {id} is no longer shared.");
+        }
+    }
+    Ok(())
+}
+
+fn handle_join(client: &KasmClient, args: JoinArgs, format: &OutputFormat) -> Result<()> {
+    let resp = client
+        .join_kasm(&args.share_id)
+        .context("failed to join session")?;
+
+    match format {
+        OutputFormat::Table | OutputFormat::Wide => {
+            println!("Joined session: {}", resp.kasm_id);
+            if let Some(owner) = &resp.username {
+                println!("Owner: {owner}");
             }
+            if let Some(url) = &resp.kasm_url {
+                println!("URL: {url}");
+            }
+        }
+        OutputFormat::Name => println!("session/{}", resp.kasm_id),
+        _ => println!("{}", output::render_value(&resp, format)?),
+    }
+    Ok(())
+}
 
-            let attempted = total - skipped;
-            eprintln!(
-                "Executed on {}/{} sessions.{}",
-                attempted - failed,
-                attempted,
-                if skipped > 0 {
-                    format!(" ({skipped} skipped)")
-                } else {
-                    String::new()
-                }
+fn handle_add(client: &KasmClient, resource: AddResource) -> Result<()> {
+    match resource {
+        AddResource::User { user, group } => {
+            let user = client
+                .resolve_user(&user)
+                .context("failed to resolve user")?;
+            let group = client
+                .resolve_group(&group)
+                .context("failed to resolve group")?;
+            client
+                .add_user_to_group(&user.user_id, &group.group_id)
+                .context("failed to add user to group")?;
+            println!(
+                "User {:?} added to group {:?}.",
+                user.username.as_deref().unwrap_or(&user.user_id),
+                group.name.as_deref().unwrap_or(&group.group_id)
+            );
+        }
+    }
+    Ok(())
+}
+
+fn handle_remove(client: &KasmClient, resource: RemoveResource) -> Result<()> {
+    match resource {
+        RemoveResource::User { user, group } => {
+            let user = client
+                .resolve_user(&user)
+                .context("failed to resolve user")?;
+            let group = client
+                .resolve_group(&group)
+                .context("failed to resolve group")?;
+            client
+                .remove_user_from_group(&user.user_id, &group.group_id)
+                .context("failed to remove user from group")?;
+            println!(
+                "User {:?} removed from group {:?}.",
+                user.username.as_deref().unwrap_or(&user.user_id),
+                group.name.as_deref().unwrap_or(&group.group_id)
             );
-            if failed > 0 {
-                anyhow::bail!("{failed} session(s) failed to execute");
-            }
         }
     }
     Ok(())
@@ -775,7 +1507,7 @@ fn handle_health(
 
 fn print_health(health: &HealthStatus, format: &OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Table => {
+        OutputFormat::Table | OutputFormat::Wide => {
             println!("Server:    {}", health.server);
             println!("Context:   {}", health.context);
             println!("Status:    {}", health.status);
@@ -789,72 +1521,58 @@ fn print_health(health: &HealthStatus, format: &OutputFormat) -> Result<()> {
                 println!("Error:     {err}");
             }
         }
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(health)?);
-        }
-        OutputFormat::Yaml => {
-            println!("{}", serde_yaml::to_string(health)?);
-        }
+        _ => println!("{}", output::render_value(health, format)?),
     }
     Ok(())
 }
 
-fn handle_top(
-    client: &KasmClient,
-    command: Option<TopCommand>,
-    format: &OutputFormat,
-) -> Result<()> {
-    match command {
+fn handle_top(client: &KasmClient, args: TopArgs, format: &OutputFormat) -> Result<()> {
+    if args.interactive {
+        if !io::stdout().is_terminal() {
+            anyhow::bail!("--interactive requires a terminal");
+        }
+        if client.is_dry_run() {
+            anyhow::bail!("--dry-run cannot be used with --interactive");
+        }
+        return dashboard::run(client, args.interval);
+    }
+
+    match args.command {
         Some(TopCommand::Agents) => {
-            let agents = client
+            let mut agents = client
                 .get_agent_report()
                 .context("failed to get agent report")?;
+            if let Some(field) = &args.sort_by {
+                sort::sort_by_field(&mut agents, field, args.reverse)?;
+            }
             println!("{}", output::render_list(&agents, format)?);
         }
         None => {
-            let kasms = client.get_kasms().context("failed to list sessions")?;
-            let sessions = kasms.len() as u64;
-            let users = {
-                let mut ids: Vec<&str> =
-                    kasms.iter().filter_map(|k| k.user_id.as_deref()).collect();
-                ids.sort_unstable();
-                ids.dedup();
-                ids.len() as u64
-            };
-            let errors = client
-                .get_report("get_errors", Some(86400), None)
-                .context("failed to get error count")?
-                .as_u64()
-                .unwrap_or(0);
-            let agents = client
-                .get_agent_report()
-                .context("failed to get agent report")?;
+            let snapshot = Snapshot::fetch(client)?;
+            let sessions = snapshot.sessions.len() as u64;
+            let users = snapshot.users();
+            let errors = snapshot.errors;
+            let mut agents = snapshot.agents;
+            if let Some(field) = &args.sort_by {
+                sort::sort_by_field(&mut agents, field, args.reverse)?;
+            }
 
             match format {
-                OutputFormat::Table => {
+                OutputFormat::Table | OutputFormat::Wide => {
                     println!(
                         "Sessions: {sessions} running    Users: {users} connected    Errors: {errors} (24h)"
                     );
                     println!();
                     println!("{}", output::render_list(&agents, format)?);
                 }
-                OutputFormat::Json => {
+                _ => {
                     let overview = TopOverview {
                         sessions,
                         users,
                         errors,
                         agents,
                     };
-                    println!("{}", serde_json::to_string_pretty(&overview)?);
-                }
-                OutputFormat::Yaml => {
-                    let overview = TopOverview {
-                        sessions,
-                        users,
-                        errors,
-                        agents,
-                    };
-                    println!("{}", serde_yaml::to_string(&overview)?);
+                    println!("{}", output::render_value(&overview, format)?);
                 }
             }
         }
@@ -862,7 +1580,21 @@ fn handle_top(
     Ok(())
 }
 
-fn handle_config(command: ConfigCommand) -> Result<()> {
+/// A certificate or key setting for `config set-context`: inline PEM is kept
+/// as is, and a file path is made absolute so the context works from any
+/// directory.
+fn pem_setting(value: &str) -> Result<String> {
+    if kasmctl::api::tls::is_inline_pem(value) {
+        return Ok(value.to_string());
+    }
+    let path = std::path::absolute(value).with_context(|| format!("invalid path {value:?}"))?;
+    if !path.is_file() {
+        anyhow::bail!("{} is not a file", path.display());
+    }
+    Ok(path.to_string_lossy().into_owned())
+}
+
+fn handle_config(command: ConfigCommand, retry_args: &RetryArgs) -> Result<()> {
     match command {
         ConfigCommand::SetContext {
             name,
@@ -870,19 +1602,48 @@ fn handle_config(command: ConfigCommand) -> Result<()> {
             api_key,
             api_secret,
             insecure,
+            certificate_authority,
+            client_certificate,
+            client_key,
         } => {
             let mut config = load_config()?;
+            let certificate_authority = certificate_authority
+                .as_deref()
+                .map(pem_setting)
+                .transpose()?;
+            let client_certificate = client_certificate.as_deref().map(pem_setting).transpose()?;
+            let client_key = client_key.as_deref().map(pem_setting).transpose()?;
 
-            // Update existing or add new context
+            // Update existing or add new context, keeping any retry policy and
+            // certificates that are not given again
             if let Some(existing) = config.contexts.iter_mut().find(|c| c.name == name) {
+                let previous = &mut existing.context;
+                let mut retry = previous.retry.take();
+                retry_args.apply(&mut retry);
+                let has_client_certificate = client_certificate.is_some();
                 existing.context = KasmContext {
                     server,
                     api_key,
                     api_secret,
                     insecure_skip_tls_verify: insecure,
                     timeout_seconds: None,
+                    retry,
+                    certificate_authority: certificate_authority
+                        .or(previous.certificate_authority.take()),
+                    client_certificate: if has_client_certificate {
+                        client_certificate
+                    } else {
+                        previous.client_certificate.take()
+                    },
+                    client_key: if has_client_certificate {
+                        client_key
+                    } else {
+                        previous.client_key.take()
+                    },
                 };
             } else {
+                let mut retry = None;
+                retry_args.apply(&mut retry);
                 config.contexts.push(NamedContext {
                     name: name.clone(),
                     context: KasmContext {
@@ -891,6 +1652,10 @@ fn handle_config(command: ConfigCommand) -> Result<()> {
                         api_secret,
                         insecure_skip_tls_verify: insecure,
                         timeout_seconds: None,
+                        retry,
+                        certificate_authority,
+                        client_certificate,
+                        client_key,
                     },
                 });
             }
@@ -0,0 +1,1250 @@
+//! Declarative resource manifests for `kasmctl apply`.
+//!
+//! A manifest file contains one or more kubectl-style documents, separated by
+//! `---` in YAML or given as a JSON array:
+//!
+//! ```yaml
+//! apiVersion: kasmctl/v1
+//! kind: Image
+//! spec:
+//!   name: kasmweb/terminal:1.18.0
+//!   friendly_name: Terminal
+//!   memory: 2GB
+//! ```
+//!
+//! Each document is matched against the existing object by ID (when the spec
+//! sets one) or by friendly name, then created or updated with only the fields
+//! that differ.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::api::KasmClient;
+use crate::api::agents::UpdateAgentRequest;
+use crate::api::error::ApiError;
+use crate::api::images::{CreateImageParams, UpdateImageRequest};
+use crate::api::servers::{CreateServerParams, UpdateServerRequest};
+use crate::api::zones::{CreateZoneParams, UpdateZoneRequest};
+use crate::cli::filters::parse_memory;
+use crate::models::agent::Agent;
+use crate::models::image::Image;
+use crate::models::server::Server;
+use crate::models::zone::Zone;
+use crate::resource::Resource;
+
+/// The only `apiVersion` currently understood by `apply`.
+pub const API_VERSION: &str = "kasmctl/v1";
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Document<S> {
+    api_version: String,
+    kind: String,
+    spec: S,
+}
+
+/// A single parsed manifest document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Manifest {
+    Image(ImageSpec),
+    Server(ServerSpec),
+    Agent(AgentSpec),
+    Zone(ZoneSpec),
+}
+
+impl Manifest {
+    /// Lower-case kind used in `kind/name` output, e.g. `image`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Manifest::Image(_) => "image",
+            Manifest::Server(_) => "server",
+            Manifest::Agent(_) => "agent",
+            Manifest::Zone(_) => "zone",
+        }
+    }
+
+    /// The `kind` value written in manifest documents, e.g. `Image`.
+    pub fn document_kind(&self) -> &'static str {
+        match self {
+            Manifest::Image(_) => "Image",
+            Manifest::Server(_) => "Server",
+            Manifest::Agent(_) => "Agent",
+            Manifest::Zone(_) => "Zone",
+        }
+    }
+
+    /// Human-readable name of the object the document describes.
+    pub fn name(&self) -> &str {
+        match self {
+            Manifest::Image(spec) => &spec.friendly_name,
+            Manifest::Server(spec) => &spec.friendly_name,
+            Manifest::Agent(spec) => spec
+                .hostname
+                .as_deref()
+                .or(spec.agent_id.as_deref())
+                .unwrap_or_default(),
+            Manifest::Zone(spec) => &spec.zone_name,
+        }
+    }
+
+    /// Render the manifest as a single YAML document.
+    pub fn to_yaml(&self) -> Result<String> {
+        fn document<S: Serialize>(kind: &str, spec: &S) -> Result<String> {
+            Ok(serde_yaml::to_string(&Document {
+                api_version: API_VERSION.to_string(),
+                kind: kind.to_string(),
+                spec,
+            })?)
+        }
+
+        let kind = self.document_kind();
+        match self {
+            Manifest::Image(spec) => document(kind, spec),
+            Manifest::Server(spec) => document(kind, spec),
+            Manifest::Agent(spec) => document(kind, spec),
+            Manifest::Zone(spec) => document(kind, spec),
+        }
+    }
+}
+
+/// Desired state of a workspace image. Mirrors [`CreateImageParams`], plus an
+/// optional `image_id` to pin the document to an existing image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImageSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<String>,
+    pub name: String,
+    pub friendly_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cores: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_memory",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub memory: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_src: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docker_registry: Option<String>,
+    /// Either a mapping or a JSON-encoded string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_config: Option<serde_json::Value>,
+    /// Either a mapping or a JSON-encoded string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exec_config: Option<serde_json::Value>,
+    /// Only used when creating; Kasm does not allow changing the type later.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_type: Option<String>,
+}
+
+/// Desired state of a server. Mirrors [`UpdateServerRequest`]; `friendly_name`
+/// is required so that the document can be matched without an ID.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_id: Option<String>,
+    pub friendly_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_port: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_info: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_simultaneous_sessions: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_simultaneous_users: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_id: Option<String>,
+}
+
+/// Desired state of a docker agent. Mirrors [`UpdateAgentRequest`]; agents are
+/// matched by `agent_id` or `hostname` and can only be updated, never created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AgentSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cores_override: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_memory",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub memory_override: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpus_override: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_prune_images: Option<String>,
+}
+
+/// Desired state of a deployment zone, matched by `zone_id` or `zone_name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ZoneSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone_id: Option<String>,
+    pub zone_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_origin_domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upstream_auth_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancing_strategy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_alternate_zones: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prioritize_static_agents: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_connections: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_port: Option<i32>,
+}
+
+/// Accept memory either as raw bytes or as a `parse_memory` string like `2GB`.
+fn deserialize_memory<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Memory {
+        Bytes(i64),
+        Text(String),
+    }
+
+    match Option::<Memory>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Memory::Bytes(bytes)) => Ok(Some(bytes)),
+        Some(Memory::Text(text)) => parse_memory(&text)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+/// Parse every document in a YAML or JSON manifest string.
+///
+/// Empty YAML documents are skipped. A top-level JSON/YAML sequence is treated
+/// as a list of documents.
+pub fn parse_manifests(content: &str) -> Result<Vec<Manifest>> {
+    let mut manifests = Vec::new();
+    for de in serde_yaml::Deserializer::from_str(content) {
+        let value = serde_yaml::Value::deserialize(de)?;
+        match value {
+            serde_yaml::Value::Null => {}
+            serde_yaml::Value::Sequence(items) => {
+                for item in items {
+                    manifests.push(parse_document(item)?);
+                }
+            }
+            other => manifests.push(parse_document(other)?),
+        }
+    }
+    Ok(manifests)
+}
+
+fn parse_document(value: serde_yaml::Value) -> Result<Manifest> {
+    let doc: Document<serde_yaml::Value> = serde_yaml::from_value(value)?;
+    if doc.api_version != API_VERSION {
+        bail!(
+            "unsupported apiVersion {:?} (expected {API_VERSION:?})",
+            doc.api_version
+        );
+    }
+
+    let manifest = match doc.kind.as_str() {
+        "Image" => Manifest::Image(
+            serde_yaml::from_value(doc.spec).context("invalid spec for kind Image")?,
+        ),
+        "Server" => Manifest::Server(
+            serde_yaml::from_value(doc.spec).context("invalid spec for kind Server")?,
+        ),
+        "Agent" => {
+            let spec: AgentSpec =
+                serde_yaml::from_value(doc.spec).context("invalid spec for kind Agent")?;
+            if spec.agent_id.is_none() && spec.hostname.is_none() {
+                bail!("Agent spec requires agent_id or hostname");
+            }
+            Manifest::Agent(spec)
+        }
+        "Zone" => {
+            Manifest::Zone(serde_yaml::from_value(doc.spec).context("invalid spec for kind Zone")?)
+        }
+        other => bail!("unsupported kind {other:?} (expected Image, Server, Agent, or Zone)"),
+    };
+    Ok(manifest)
+}
+
+/// Load manifests from a file, a directory of `.yaml`/`.yml`/`.json` files
+/// (read in name order), or stdin when the path is `-`.
+pub fn load_manifests(path: &Path) -> Result<Vec<Manifest>> {
+    if path == Path::new("-") {
+        let mut content = String::new();
+        io::stdin()
+            .read_to_string(&mut content)
+            .context("failed to read manifests from stdin")?;
+        return parse_manifests(&content).context("failed to parse manifests from stdin");
+    }
+
+    if path.is_dir() {
+        let mut files: Vec<_> = fs::read_dir(path)
+            .with_context(|| format!("failed to read directory {}", path.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| matches!(ext, "yaml" | "yml" | "json"))
+            })
+            .collect();
+        files.sort();
+
+        let mut manifests = Vec::new();
+        for file in files {
+            manifests.extend(load_manifests(&file)?);
+        }
+        return Ok(manifests);
+    }
+
+    let content =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    parse_manifests(&content).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+// --- Planning ---
+
+/// Return the desired value when it is set and differs from the current one.
+fn changed<T: PartialEq + Clone>(desired: &Option<T>, current: &Option<T>) -> Option<T> {
+    match desired {
+        Some(value) if current.as_ref() != Some(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+/// Normalize a run/exec config so that a JSON-encoded string and the
+/// equivalent mapping compare equal.
+pub(crate) fn normalize_config(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => {
+            serde_json::from_str(s).unwrap_or_else(|_| serde_json::Value::String(s.clone()))
+        }
+        other => other.clone(),
+    }
+}
+
+/// Kasm accepts run/exec configs as JSON-encoded strings.
+fn config_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn changed_config(
+    desired: &Option<serde_json::Value>,
+    current: &Option<serde_json::Value>,
+) -> Option<String> {
+    let desired = desired.as_ref()?;
+    let unchanged = current
+        .as_ref()
+        .is_some_and(|current| normalize_config(current) == normalize_config(desired));
+    (!unchanged).then(|| config_string(desired))
+}
+
+/// True when an update request carries any field besides its ID.
+///
+/// All update requests skip unset fields when serialized, so a request with a
+/// single key only identifies the target and would change nothing.
+fn has_changes<T: Serialize>(req: &T) -> bool {
+    serde_json::to_value(req)
+        .ok()
+        .and_then(|v| v.as_object().map(|o| o.len() > 1))
+        .unwrap_or(false)
+}
+
+/// Find the single object whose name matches (case-insensitive).
+fn find_by_name<T>(
+    items: &[T],
+    name: &str,
+    kind: &str,
+    item_name: impl Fn(&T) -> Option<&str>,
+) -> Result<Option<usize>> {
+    let lower = name.to_lowercase();
+    let matches: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item_name(item).is_some_and(|n| n.to_lowercase() == lower))
+        .map(|(i, _)| i)
+        .collect();
+    match matches.as_slice() {
+        [] => Ok(None),
+        [index] => Ok(Some(*index)),
+        _ => Err(ApiError::Ambiguous(format!(
+            "ambiguous {kind} name {name:?}: matches {} {kind}s; set an ID in the spec",
+            matches.len()
+        ))
+        .into()),
+    }
+}
+
+/// Find the single object with an exact ID match.
+fn find_by_id<T>(items: &[T], id: &str, kind: &str, item_id: impl Fn(&T) -> &str) -> Result<usize> {
+    items
+        .iter()
+        .position(|item| item_id(item) == id)
+        .ok_or_else(|| ApiError::NotFound(format!("{kind} {id:?} not found")).into())
+}
+
+impl ImageSpec {
+    /// Locate the existing image this spec refers to, if any.
+    pub fn find(&self, images: &[Image]) -> Result<Option<usize>> {
+        match &self.image_id {
+            Some(id) => find_by_id(images, id, "image", |i| &i.image_id).map(Some),
+            None => find_by_name(images, &self.friendly_name, "image", |i| {
+                i.friendly_name.as_deref()
+            }),
+        }
+    }
+
+    pub fn create_params(&self) -> CreateImageParams {
+        CreateImageParams {
+            name: self.name.clone(),
+            friendly_name: self.friendly_name.clone(),
+            description: self.description.clone(),
+            cores: self.cores,
+            memory: self.memory,
+            enabled: self.enabled.unwrap_or(true),
+            image_src: self
+                .image_src
+                .clone()
+                .unwrap_or_else(|| "Container".to_string()),
+            docker_registry: self.docker_registry.clone(),
+            run_config: self.run_config.as_ref().map(config_string),
+            exec_config: self.exec_config.as_ref().map(config_string),
+            image_type: self.image_type.clone(),
+        }
+    }
+
+    /// Build an update containing only the fields that differ from `existing`,
+    /// or `None` when the image already matches the spec.
+    pub fn update_request(&self, existing: &Image) -> Option<UpdateImageRequest> {
+        let req = UpdateImageRequest {
+            image_id: existing.image_id.clone(),
+            name: changed(&Some(self.name.clone()), &existing.name),
+            friendly_name: changed(&Some(self.friendly_name.clone()), &existing.friendly_name),
+            description: changed(&self.description, &existing.description),
+            cores: changed(&self.cores, &existing.cores),
+            memory: changed(&self.memory, &existing.memory),
+            enabled: changed(&self.enabled, &existing.enabled),
+            image_src: changed(&self.image_src, &existing.image_src),
+            docker_registry: changed(&self.docker_registry, &existing.docker_registry),
+            run_config: changed_config(&self.run_config, &existing.run_config),
+            exec_config: changed_config(&self.exec_config, &existing.exec_config),
+            hidden: None,
+        };
+        has_changes(&req).then_some(req)
+    }
+}
+
+impl ServerSpec {
+    /// Locate the existing server this spec refers to, if any.
+    pub fn find(&self, servers: &[Server]) -> Result<Option<usize>> {
+        match &self.server_id {
+            Some(id) => find_by_id(servers, id, "server", |s| &s.server_id).map(Some),
+            None => find_by_name(servers, &self.friendly_name, "server", |s| {
+                s.friendly_name.as_deref()
+            }),
+        }
+    }
+
+    /// Build create parameters, failing if a field required by Kasm is missing.
+    pub fn create_params(&self) -> Result<CreateServerParams> {
+        let missing = |field: &str| anyhow!("creating a server requires spec.{field}");
+        Ok(CreateServerParams {
+            friendly_name: self.friendly_name.clone(),
+            hostname: self.hostname.clone().ok_or_else(|| missing("hostname"))?,
+            connection_type: self
+                .connection_type
+                .clone()
+                .ok_or_else(|| missing("connection_type"))?,
+            connection_port: self
+                .connection_port
+                .ok_or_else(|| missing("connection_port"))?,
+            zone_id: self.zone_id.clone().ok_or_else(|| missing("zone_id"))?,
+            enabled: self.enabled.unwrap_or(true),
+            connection_username: self.connection_username.clone(),
+            connection_info: self.connection_info.clone(),
+            max_simultaneous_sessions: self.max_simultaneous_sessions,
+            max_simultaneous_users: self.max_simultaneous_users,
+            pool_id: self.pool_id.clone(),
+        })
+    }
+
+    /// Build an update containing only the fields that differ from `existing`,
+    /// or `None` when the server already matches the spec.
+    pub fn update_request(&self, existing: &Server) -> Option<UpdateServerRequest> {
+        let req = UpdateServerRequest {
+            server_id: existing.server_id.clone(),
+            friendly_name: changed(&Some(self.friendly_name.clone()), &existing.friendly_name),
+            hostname: changed(&self.hostname, &existing.hostname),
+            enabled: changed(&self.enabled, &existing.enabled),
+            connection_type: changed(&self.connection_type, &existing.connection_type),
+            connection_port: changed(&self.connection_port, &existing.connection_port),
+            connection_username: changed(&self.connection_username, &existing.connection_username),
+            connection_info: changed(&self.connection_info, &existing.connection_info),
+            max_simultaneous_sessions: changed(
+                &self.max_simultaneous_sessions,
+                &existing.max_simultaneous_sessions,
+            ),
+            max_simultaneous_users: changed(
+                &self.max_simultaneous_users,
+                &existing.max_simultaneous_users,
+            ),
+            zone_id: changed(&self.zone_id, &existing.zone_id),
+            pool_id: changed(&self.pool_id, &existing.pool_id),
+        };
+        has_changes(&req).then_some(req)
+    }
+}
+
+impl AgentSpec {
+    /// Locate the existing agent this spec refers to. Agents cannot be created,
+    /// so a missing agent is an error.
+    pub fn find(&self, agents: &[Agent]) -> Result<usize> {
+        if let Some(id) = &self.agent_id {
+            return find_by_id(agents, id, "agent", |a| &a.agent_id);
+        }
+        let hostname = self.hostname.as_deref().unwrap_or_default();
+        find_by_name(agents, hostname, "agent", |a| a.hostname.as_deref())?.ok_or_else(|| {
+            ApiError::NotFound(format!(
+                "agent {hostname:?} not found (agents cannot be created)"
+            ))
+            .into()
+        })
+    }
+
+    /// Build an update containing only the fields that differ from `existing`,
+    /// or `None` when the agent already matches the spec.
+    pub fn update_request(&self, existing: &Agent) -> Option<UpdateAgentRequest> {
+        let req = UpdateAgentRequest {
+            agent_id: existing.agent_id.clone(),
+            enabled: changed(&self.enabled, &existing.enabled),
+            cores_override: changed(&self.cores_override, &existing.cores_override),
+            memory_override: changed(&self.memory_override, &existing.memory_override),
+            gpus_override: changed(&self.gpus_override, &existing.gpus_override),
+            auto_prune_images: changed(&self.auto_prune_images, &existing.auto_prune_images),
+        };
+        has_changes(&req).then_some(req)
+    }
+}
+
+impl ZoneSpec {
+    /// Locate the existing zone this spec refers to, if any.
+    pub fn find(&self, zones: &[Zone]) -> Result<Option<usize>> {
+        match &self.zone_id {
+            Some(id) => find_by_id(zones, id, "zone", |z| &z.zone_id).map(Some),
+            None => find_by_name(zones, &self.zone_name, "zone", |z| z.zone_name.as_deref()),
+        }
+    }
+
+    pub fn create_params(&self) -> CreateZoneParams {
+        CreateZoneParams {
+            zone_name: self.zone_name.clone(),
+            allow_origin_domain: self.allow_origin_domain.clone(),
+            upstream_auth_address: self.upstream_auth_address.clone(),
+            load_balancing_strategy: self.load_balancing_strategy.clone(),
+            search_alternate_zones: self.search_alternate_zones,
+            prioritize_static_agents: self.prioritize_static_agents,
+            proxy_connections: self.proxy_connections,
+            proxy_hostname: self.proxy_hostname.clone(),
+            proxy_path: self.proxy_path.clone(),
+            proxy_port: self.proxy_port,
+        }
+    }
+
+    /// Build an update containing only the fields that differ from `existing`,
+    /// or `None` when the zone already matches the spec.
+    pub fn update_request(&self, existing: &Zone) -> Option<UpdateZoneRequest> {
+        let req = UpdateZoneRequest {
+            zone_id: existing.zone_id.clone(),
+            zone_name: changed(&Some(self.zone_name.clone()), &existing.zone_name),
+            allow_origin_domain: changed(&self.allow_origin_domain, &existing.allow_origin_domain),
+            upstream_auth_address: changed(
+                &self.upstream_auth_address,
+                &existing.upstream_auth_address,
+            ),
+            load_balancing_strategy: changed(
+                &self.load_balancing_strategy,
+                &existing.load_balancing_strategy,
+            ),
+            search_alternate_zones: changed(
+                &self.search_alternate_zones,
+                &existing.search_alternate_zones,
+            ),
+            prioritize_static_agents: changed(
+                &self.prioritize_static_agents,
+                &existing.prioritize_static_agents,
+            ),
+            proxy_connections: changed(&self.proxy_connections, &existing.proxy_connections),
+            proxy_hostname: changed(&self.proxy_hostname, &existing.proxy_hostname),
+            proxy_path: changed(&self.proxy_path, &existing.proxy_path),
+            proxy_port: changed(&self.proxy_port, &existing.proxy_port),
+        };
+        has_changes(&req).then_some(req)
+    }
+}
+
+// --- Exporting ---
+
+impl ImageSpec {
+    /// Build a spec from a live image, dropping the server-assigned ID.
+    /// Returns `None` when the image lacks the names needed to re-apply it.
+    pub fn from_live(image: &Image) -> Option<Self> {
+        Some(Self {
+            image_id: None,
+            name: image.name.clone()?,
+            friendly_name: image.friendly_name.clone()?,
+            description: image.description.clone(),
+            cores: image.cores,
+            memory: image.memory,
+            enabled: image.enabled,
+            image_src: image.image_src.clone(),
+            docker_registry: image.docker_registry.clone(),
+            run_config: image.run_config.as_ref().map(normalize_config),
+            exec_config: image.exec_config.as_ref().map(normalize_config),
+            image_type: image.image_type.clone(),
+        })
+    }
+}
+
+impl ServerSpec {
+    /// Build a spec from a live server, dropping the server-assigned ID.
+    /// `connection_info` often holds credentials and is only kept when
+    /// `include_secrets` is set. Returns `None` for servers without a name.
+    pub fn from_live(server: &Server, include_secrets: bool) -> Option<Self> {
+        Some(Self {
+            server_id: None,
+            friendly_name: server.friendly_name.clone()?,
+            hostname: server.hostname.clone(),
+            enabled: server.enabled,
+            connection_type: server.connection_type.clone(),
+            connection_port: server.connection_port,
+            connection_username: server.connection_username.clone(),
+            connection_info: server.connection_info.clone().filter(|_| include_secrets),
+            max_simultaneous_sessions: server.max_simultaneous_sessions,
+            max_simultaneous_users: server.max_simultaneous_users,
+            zone_id: server.zone_id.clone(),
+            pool_id: server.pool_id.clone(),
+        })
+    }
+}
+
+impl AgentSpec {
+    /// Build a spec from a live agent. Agents are matched by hostname, so the
+    /// ID is only kept when the agent has no hostname.
+    pub fn from_live(agent: &Agent) -> Self {
+        Self {
+            agent_id: agent.hostname.is_none().then(|| agent.agent_id.clone()),
+            hostname: agent.hostname.clone(),
+            enabled: agent.enabled,
+            cores_override: agent.cores_override,
+            memory_override: agent.memory_override,
+            gpus_override: agent.gpus_override,
+            auto_prune_images: agent.auto_prune_images.clone(),
+        }
+    }
+}
+
+impl ZoneSpec {
+    /// Build a spec from a live zone, dropping the server-assigned ID.
+    /// Returns `None` for zones without a name.
+    pub fn from_live(zone: &Zone) -> Option<Self> {
+        Some(Self {
+            zone_id: None,
+            zone_name: zone.zone_name.clone()?,
+            allow_origin_domain: zone.allow_origin_domain.clone(),
+            upstream_auth_address: zone.upstream_auth_address.clone(),
+            load_balancing_strategy: zone.load_balancing_strategy.clone(),
+            search_alternate_zones: zone.search_alternate_zones,
+            prioritize_static_agents: zone.prioritize_static_agents,
+            proxy_connections: zone.proxy_connections,
+            proxy_hostname: zone.proxy_hostname.clone(),
+            proxy_path: zone.proxy_path.clone(),
+            proxy_port: zone.proxy_port,
+        })
+    }
+}
+
+/// Reduce a name to lower-case ASCII letters, digits, and single dashes.
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "unnamed".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Render each manifest to its own YAML file, named `<kind>-<name>.yaml`.
+/// Names that collide after slugifying get a numeric suffix.
+pub fn export_files(manifests: &[Manifest]) -> Result<Vec<(String, String)>> {
+    let mut used = HashSet::new();
+    manifests
+        .iter()
+        .map(|m| {
+            let base = format!("{}-{}", m.kind(), slugify(m.name()));
+            let mut file_name = format!("{base}.yaml");
+            let mut n = 2;
+            while !used.insert(file_name.clone()) {
+                file_name = format!("{base}-{n}.yaml");
+                n += 1;
+            }
+            Ok((file_name, m.to_yaml()?))
+        })
+        .collect()
+}
+
+// --- Applying ---
+
+/// What `apply` did with a single manifest document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApplyAction {
+    Created,
+    Configured,
+    Unchanged,
+}
+
+impl fmt::Display for ApplyAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ApplyAction::Created => "created",
+            ApplyAction::Configured => "configured",
+            ApplyAction::Unchanged => "unchanged",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Outcome of applying one manifest document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApplyResult {
+    pub kind: String,
+    pub name: String,
+    pub action: ApplyAction,
+}
+
+impl fmt::Display for ApplyResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} {}", self.kind, self.name, self.action)
+    }
+}
+
+impl Resource for ApplyResult {
+    fn resource_name() -> &'static str {
+        "Object"
+    }
+
+    fn table_headers() -> Vec<&'static str> {
+        vec!["KIND", "NAME", "ACTION"]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        vec![
+            self.kind.clone(),
+            self.name.clone(),
+            self.action.to_string(),
+        ]
+    }
+
+    fn object_name(&self) -> String {
+        format!("{}/{}", self.kind, self.name)
+    }
+}
+
+/// Live resource listings, each fetched from the server at most once.
+pub(crate) struct LiveObjects {
+    images: Option<Vec<Image>>,
+    servers: Option<Vec<Server>>,
+    agents: Option<Vec<Agent>>,
+    zones: Option<Vec<Zone>>,
+}
+
+impl LiveObjects {
+    pub(crate) fn new() -> Self {
+        Self {
+            images: None,
+            servers: None,
+            agents: None,
+            zones: None,
+        }
+    }
+
+    pub(crate) fn zones(&mut self, client: &KasmClient) -> Result<&mut Vec<Zone>> {
+        if self.zones.is_none() {
+            self.zones = Some(client.get_zones()?);
+        }
+        Ok(self.zones.as_mut().expect("zones were just fetched"))
+    }
+
+    pub(crate) fn images(&mut self, client: &KasmClient) -> Result<&mut Vec<Image>> {
+        if self.images.is_none() {
+            self.images = Some(client.get_images()?);
+        }
+        Ok(self.images.as_mut().expect("images were just fetched"))
+    }
+
+    pub(crate) fn servers(&mut self, client: &KasmClient) -> Result<&mut Vec<Server>> {
+        if self.servers.is_none() {
+            self.servers = Some(client.get_servers()?);
+        }
+        Ok(self.servers.as_mut().expect("servers were just fetched"))
+    }
+
+    pub(crate) fn agents(&mut self, client: &KasmClient) -> Result<&mut Vec<Agent>> {
+        if self.agents.is_none() {
+            self.agents = Some(client.get_agents()?);
+        }
+        Ok(self.agents.as_mut().expect("agents were just fetched"))
+    }
+}
+
+/// Applies manifests against a server, keeping the live listings current as
+/// objects are created or updated so later documents see earlier changes.
+pub struct Applier<'a> {
+    client: &'a KasmClient,
+    live: LiveObjects,
+}
+
+impl<'a> Applier<'a> {
+    pub fn new(client: &'a KasmClient) -> Self {
+        Self {
+            client,
+            live: LiveObjects::new(),
+        }
+    }
+
+    pub fn apply(&mut self, manifest: &Manifest) -> Result<ApplyResult> {
+        let action = match manifest {
+            Manifest::Image(spec) => self.apply_image(spec)?,
+            Manifest::Server(spec) => self.apply_server(spec)?,
+            Manifest::Agent(spec) => self.apply_agent(spec)?,
+            Manifest::Zone(spec) => self.apply_zone(spec)?,
+        };
+        Ok(ApplyResult {
+            kind: manifest.kind().to_string(),
+            name: manifest.name().to_string(),
+            action,
+        })
+    }
+
+    fn apply_image(&mut self, spec: &ImageSpec) -> Result<ApplyAction> {
+        let images = self.live.images(self.client)?;
+
+        match spec.find(images)? {
+            None => {
+                let image = self.client.create_image(&spec.create_params())?;
+                images.push(image);
+                Ok(ApplyAction::Created)
+            }
+            Some(index) => match spec.update_request(&images[index]) {
+                None => Ok(ApplyAction::Unchanged),
+                Some(req) => {
+                    images[index] = self.client.update_image(&req)?;
+                    Ok(ApplyAction::Configured)
+                }
+            },
+        }
+    }
+
+    fn apply_server(&mut self, spec: &ServerSpec) -> Result<ApplyAction> {
+        let servers = self.live.servers(self.client)?;
+
+        match spec.find(servers)? {
+            None => {
+                let server = self.client.create_server(&spec.create_params()?)?;
+                servers.push(server);
+                Ok(ApplyAction::Created)
+            }
+            Some(index) => match spec.update_request(&servers[index]) {
+                None => Ok(ApplyAction::Unchanged),
+                Some(req) => {
+                    servers[index] = self.client.update_server(&req)?;
+                    Ok(ApplyAction::Configured)
+                }
+            },
+        }
+    }
+
+    fn apply_zone(&mut self, spec: &ZoneSpec) -> Result<ApplyAction> {
+        let zones = self.live.zones(self.client)?;
+
+        match spec.find(zones)? {
+            None => {
+                let zone = self.client.create_zone(&spec.create_params())?;
+                zones.push(zone);
+                Ok(ApplyAction::Created)
+            }
+            Some(index) => match spec.update_request(&zones[index]) {
+                None => Ok(ApplyAction::Unchanged),
+                Some(req) => {
+                    zones[index] = self.client.update_zone(&req)?;
+                    Ok(ApplyAction::Configured)
+                }
+            },
+        }
+    }
+
+    fn apply_agent(&mut self, spec: &AgentSpec) -> Result<ApplyAction> {
+        let agents = self.live.agents(self.client)?;
+
+        let index = spec.find(agents)?;
+        match spec.update_request(&agents[index]) {
+            None => Ok(ApplyAction::Unchanged),
+            Some(req) => {
+                agents[index] = self.client.update_agent(&req)?;
+                Ok(ApplyAction::Configured)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image_spec(yaml: &str) -> ImageSpec {
+        match parse_manifests(yaml).unwrap().remove(0) {
+            Manifest::Image(spec) => spec,
+            other => panic!("expected Image manifest, got {other:?}"),
+        }
+    }
+
+    const TERMINAL: &str = r#"
+apiVersion: kasmctl/v1
+kind: Image
+spec:
+  name: kasmweb/terminal:1.18.0
+  friendly_name: Terminal
+  cores: 2
+  memory: 2GB
+  run_config:
+    hostname: kasm
+"#;
+
+    fn existing_terminal() -> Image {
+        Image {
+            image_id: "img-1".into(),
+            name: Some("kasmweb/terminal:1.18.0".into()),
+            friendly_name: Some("Terminal".into()),
+            cores: Some(2.0),
+            memory: Some(2_147_483_648),
+            run_config: Some(serde_json::Value::String(r#"{"hostname": "kasm"}"#.into())),
+            ..Default::default()
+        }
+    }
+
+    // --- parse_manifests() tests ---
+
+    #[test]
+    fn parse_multi_document_yaml() {
+        let yaml = r#"
+apiVersion: kasmctl/v1
+kind: Image
+spec:
+  name: kasmweb/terminal:1.18.0
+  friendly_name: Terminal
+---
+apiVersion: kasmctl/v1
+kind: Server
+spec:
+  friendly_name: Build Box
+  hostname: 10.0.0.5
+---
+apiVersion: kasmctl/v1
+kind: Agent
+spec:
+  hostname: agent-01
+  enabled: false
+---
+"#;
+        let manifests = parse_manifests(yaml).unwrap();
+        assert_eq!(manifests.len(), 3);
+        assert_eq!(manifests[0].kind(), "image");
+        assert_eq!(manifests[1].name(), "Build Box");
+        assert_eq!(manifests[2].name(), "agent-01");
+    }
+
+    #[test]
+    fn parse_json_array() {
+        let json = r#"[
+            {"apiVersion": "kasmctl/v1", "kind": "Agent", "spec": {"agent_id": "agent-1"}},
+            {"apiVersion": "kasmctl/v1", "kind": "Agent", "spec": {"agent_id": "agent-2"}}
+        ]"#;
+        let manifests = parse_manifests(json).unwrap();
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests[1].name(), "agent-2");
+    }
+
+    #[test]
+    fn parse_memory_accepts_human_and_raw_values() {
+        let spec = image_spec(TERMINAL);
+        assert_eq!(spec.memory, Some(2_147_483_648));
+
+        let raw = TERMINAL.replace("memory: 2GB", "memory: 1024");
+        assert_eq!(image_spec(&raw).memory, Some(1024));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_kind() {
+        let yaml = "apiVersion: kasmctl/v1\nkind: Session\nspec: {}\n";
+        let err = parse_manifests(yaml).unwrap_err().to_string();
+        assert!(err.contains("unsupported kind"), "error was: {err}");
+    }
+
+    #[test]
+    fn parse_rejects_wrong_api_version() {
+        let yaml = TERMINAL.replace("kasmctl/v1", "kasmctl/v2");
+        let err = parse_manifests(&yaml).unwrap_err().to_string();
+        assert!(err.contains("unsupported apiVersion"), "error was: {err}");
+    }
+
+    #[test]
+    fn parse_rejects_unknown_spec_field() {
+        let yaml = TERMINAL.replace("cores: 2", "corez: 2");
+        let err = format!("{:#}", parse_manifests(&yaml).unwrap_err());
+        assert!(err.contains("corez"), "error was: {err}");
+    }
+
+    #[test]
+    fn parse_agent_requires_identifier() {
+        let yaml = "apiVersion: kasmctl/v1\nkind: Agent\nspec:\n  enabled: true\n";
+        assert!(parse_manifests(yaml).is_err());
+    }
+
+    // --- planning tests ---
+
+    #[test]
+    fn image_find_by_friendly_name_case_insensitive() {
+        let spec = image_spec(&TERMINAL.replace("Terminal", "TERMINAL"));
+        let images = vec![existing_terminal()];
+        assert_eq!(spec.find(&images).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn image_find_by_unknown_id_errors() {
+        let mut spec = image_spec(TERMINAL);
+        spec.image_id = Some("missing".into());
+        assert!(spec.find(&[existing_terminal()]).is_err());
+    }
+
+    #[test]
+    fn image_matching_spec_is_unchanged() {
+        let spec = image_spec(TERMINAL);
+        assert!(spec.update_request(&existing_terminal()).is_none());
+    }
+
+    #[test]
+    fn image_update_only_contains_changed_fields() {
+        let spec = image_spec(&TERMINAL.replace("cores: 2", "cores: 4"));
+        let req = spec.update_request(&existing_terminal()).unwrap();
+        assert_eq!(req.image_id, "img-1");
+        assert_eq!(req.cores, Some(4.0));
+        assert!(req.name.is_none());
+        assert!(req.memory.is_none());
+        assert!(req.run_config.is_none());
+    }
+
+    #[test]
+    fn image_run_config_change_is_sent_as_json_string() {
+        let spec = image_spec(&TERMINAL.replace("hostname: kasm", "hostname: other"));
+        let req = spec.update_request(&existing_terminal()).unwrap();
+        assert_eq!(req.run_config.as_deref(), Some(r#"{"hostname":"other"}"#));
+    }
+
+    #[test]
+    fn image_create_params_apply_defaults() {
+        let params = image_spec(TERMINAL).create_params();
+        assert!(params.enabled);
+        assert_eq!(params.image_src, "Container");
+        assert_eq!(params.run_config.as_deref(), Some(r#"{"hostname":"kasm"}"#));
+    }
+
+    #[test]
+    fn server_create_requires_connection_fields() {
+        let yaml =
+            "apiVersion: kasmctl/v1\nkind: Server\nspec:\n  friendly_name: Box\n  hostname: h\n";
+        let Manifest::Server(spec) = parse_manifests(yaml).unwrap().remove(0) else {
+            panic!("expected Server manifest");
+        };
+        let err = spec
+            .create_params()
+            .err()
+            .expect("create should fail without connection fields")
+            .to_string();
+        assert!(err.contains("connection_type"), "error was: {err}");
+    }
+
+    #[test]
+    fn server_update_detects_changes() {
+        let yaml =
+            "apiVersion: kasmctl/v1\nkind: Server\nspec:\n  friendly_name: Box\n  enabled: false\n";
+        let Manifest::Server(spec) = parse_manifests(yaml).unwrap().remove(0) else {
+            panic!("expected Server manifest");
+        };
+        let existing = Server {
+            server_id: "srv-1".into(),
+            friendly_name: Some("Box".into()),
+            enabled: Some(true),
+            ..Default::default()
+        };
+        let req = spec.update_request(&existing).unwrap();
+        assert_eq!(req.enabled, Some(false));
+        assert!(req.friendly_name.is_none());
+    }
+
+    #[test]
+    fn zone_update_detects_changes() {
+        let yaml =
+            "apiVersion: kasmctl/v1\nkind: Zone\nspec:\n  zone_name: us-east\n  proxy_port: 8443\n";
+        let Manifest::Zone(spec) = parse_manifests(yaml).unwrap().remove(0) else {
+            panic!("expected Zone manifest");
+        };
+        let existing = Zone {
+            zone_id: "zone-1".into(),
+            zone_name: Some("US-East".into()),
+            proxy_port: Some(443),
+            ..Default::default()
+        };
+        let req = spec.update_request(&existing).unwrap();
+        assert_eq!(req.zone_id, "zone-1");
+        assert_eq!(req.proxy_port, Some(8443));
+        assert_eq!(req.zone_name.as_deref(), Some("us-east"));
+
+        let unchanged = Zone {
+            zone_name: Some("us-east".into()),
+            proxy_port: Some(8443),
+            ..existing
+        };
+        assert!(spec.update_request(&unchanged).is_none());
+    }
+
+    #[test]
+    fn agent_missing_is_an_error() {
+        let yaml = "apiVersion: kasmctl/v1\nkind: Agent\nspec:\n  hostname: agent-09\n";
+        let Manifest::Agent(spec) = parse_manifests(yaml).unwrap().remove(0) else {
+            panic!("expected Agent manifest");
+        };
+        let err = spec.find(&[]).unwrap_err().to_string();
+        assert!(err.contains("cannot be created"), "error was: {err}");
+    }
+
+    // --- export tests ---
+
+    #[test]
+    fn exported_image_round_trips_through_parse() {
+        let image = existing_terminal();
+        let spec = ImageSpec::from_live(&image).unwrap();
+        assert!(spec.image_id.is_none());
+
+        let files = export_files(&[Manifest::Image(spec.clone())]).unwrap();
+        assert_eq!(files[0].0, "image-terminal.yaml");
+        assert!(!files[0].1.contains("image_id"));
+
+        let parsed = parse_manifests(&files[0].1).unwrap();
+        assert_eq!(parsed, vec![Manifest::Image(spec.clone())]);
+        // Re-applying an export against the same image changes nothing.
+        assert!(spec.update_request(&image).is_none());
+    }
+
+    #[test]
+    fn exported_server_redacts_connection_info_by_default() {
+        let server = Server {
+            server_id: "srv-1".into(),
+            friendly_name: Some("Build Box".into()),
+            connection_info: Some("password=hunter2".into()),
+            ..Default::default()
+        };
+        let redacted = ServerSpec::from_live(&server, false).unwrap();
+        assert!(redacted.connection_info.is_none());
+        let yaml = Manifest::Server(redacted).to_yaml().unwrap();
+        assert!(!yaml.contains("hunter2"));
+
+        let full = ServerSpec::from_live(&server, true).unwrap();
+        assert_eq!(full.connection_info.as_deref(), Some("password=hunter2"));
+    }
+
+    #[test]
+    fn exported_agent_is_matched_by_hostname() {
+        let agent = Agent {
+            agent_id: "agent-1".into(),
+            hostname: Some("agent-01".into()),
+            enabled: Some(true),
+            ..Default::default()
+        };
+        let spec = AgentSpec::from_live(&agent);
+        assert!(spec.agent_id.is_none());
+        assert_eq!(spec.find(&[agent]).unwrap(), 0);
+    }
+
+    #[test]
+    fn export_file_names_are_unique_slugs() {
+        let zone = |name: &str| {
+            Manifest::Zone(
+                ZoneSpec::from_live(&Zone {
+                    zone_id: "z".into(),
+                    zone_name: Some(name.into()),
+                    ..Default::default()
+                })
+                .unwrap(),
+            )
+        };
+        let files = export_files(&[zone("US East"), zone("us-east"), zone("???")]).unwrap();
+        let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "zone-us-east.yaml",
+                "zone-us-east-2.yaml",
+                "zone-unnamed.yaml"
+            ]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::output::display::{format_bytes, short_id};
-use crate::resource::Resource;
+use crate::resource::{Identified, Resource};
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Agent {
@@ -54,6 +54,28 @@ impl Resource for Agent {
         ]
     }
 
+    fn wide_headers() -> Vec<&'static str> {
+        let mut headers = Self::table_headers();
+        headers.extend(["SERVER", "ZONE", "GPUS"]);
+        headers
+    }
+
+    fn wide_row(&self) -> Vec<String> {
+        let mut row = self.table_row();
+        row.extend([
+            self.server_id
+                .as_deref()
+                .map(|id| short_id(id).to_string())
+                .unwrap_or_default(),
+            self.zone_id
+                .as_deref()
+                .map(|id| short_id(id).to_string())
+                .unwrap_or_default(),
+            self.gpus.map(|v| v.to_string()).unwrap_or_default(),
+        ]);
+        row
+    }
+
     fn table_detail(&self) -> Vec<(&'static str, String)> {
         vec![
             ("AGENT ID", self.agent_id.clone()),
@@ -94,3 +116,9 @@ impl Resource for Agent {
         ]
     }
 }
+
+impl Identified for Agent {
+    fn resource_id(&self) -> &str {
+        &self.agent_id
+    }
+}
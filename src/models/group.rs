@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::output::display::short_id;
+use crate::resource::{Identified, Resource};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Group {
+    pub group_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+    #[serde(default)]
+    pub is_system: Option<bool>,
+}
+
+impl Resource for Group {
+    fn resource_name() -> &'static str {
+        "Group"
+    }
+
+    fn table_headers() -> Vec<&'static str> {
+        vec!["GROUP ID", "NAME", "PRIORITY", "SYSTEM", "DESCRIPTION"]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        vec![
+            short_id(&self.group_id).to_string(),
+            self.name.clone().unwrap_or_default(),
+            self.priority.map(|v| v.to_string()).unwrap_or_default(),
+            self.is_system.map(|v| v.to_string()).unwrap_or_default(),
+            self.description.clone().unwrap_or_default(),
+        ]
+    }
+
+    fn table_detail(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("GROUP ID", self.group_id.clone()),
+            ("NAME", self.name.clone().unwrap_or_default()),
+            ("DESCRIPTION", self.description.clone().unwrap_or_default()),
+            (
+                "PRIORITY",
+                self.priority.map(|v| v.to_string()).unwrap_or_default(),
+            ),
+            (
+                "SYSTEM",
+                self.is_system.map(|v| v.to_string()).unwrap_or_default(),
+            ),
+        ]
+    }
+}
+
+impl Identified for Group {
+    fn resource_id(&self) -> &str {
+        &self.group_id
+    }
+}
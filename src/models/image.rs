@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::output::display::{format_bytes, short_id};
-use crate::resource::Resource;
+use crate::resource::{Identified, Resource};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Image {
     pub image_id: String,
     #[serde(default)]
@@ -20,6 +20,19 @@ pub struct Image {
     pub memory: Option<i64>,
     #[serde(default)]
     pub image_src: Option<String>,
+    #[serde(default)]
+    pub docker_registry: Option<String>,
+    #[serde(default)]
+    pub image_type: Option<String>,
+    #[serde(default)]
+    pub hidden: Option<bool>,
+    /// Docker run configuration. Kasm may return this either as a JSON object
+    /// or as a JSON-encoded string, so it is kept as a raw value.
+    #[serde(default)]
+    pub run_config: Option<serde_json::Value>,
+    /// Docker exec configuration; same representation as `run_config`.
+    #[serde(default)]
+    pub exec_config: Option<serde_json::Value>,
 }
 
 impl Resource for Image {
@@ -49,6 +62,22 @@ impl Resource for Image {
         ]
     }
 
+    fn wide_headers() -> Vec<&'static str> {
+        let mut headers = Self::table_headers();
+        headers.extend(["TYPE", "REGISTRY", "HIDDEN"]);
+        headers
+    }
+
+    fn wide_row(&self) -> Vec<String> {
+        let mut row = self.table_row();
+        row.extend([
+            self.image_type.clone().unwrap_or_default(),
+            self.docker_registry.clone().unwrap_or_default(),
+            self.hidden.map(|v| v.to_string()).unwrap_or_default(),
+        ]);
+        row
+    }
+
     fn table_detail(&self) -> Vec<(&'static str, String)> {
         vec![
             ("IMAGE ID", self.image_id.clone()),
@@ -71,3 +100,9 @@ impl Resource for Image {
         ]
     }
 }
+
+impl Identified for Image {
+    fn resource_id(&self) -> &str {
+        &self.image_id
+    }
+}
@@ -1,6 +1,9 @@
 pub mod agent;
+pub mod group;
 pub mod image;
 pub mod report;
 pub mod server;
 pub mod session;
+pub mod stats;
+pub mod user;
 pub mod zone;
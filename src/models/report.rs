@@ -74,6 +74,27 @@ impl Resource for AgentResourceReport {
         ]
     }
 
+    fn wide_headers() -> Vec<&'static str> {
+        let mut headers = Self::table_headers();
+        headers.extend(["SERVER", "MEMORY FREE", "DISK FREE"]);
+        headers
+    }
+
+    fn wide_row(&self) -> Vec<String> {
+        let mut row = self.table_row();
+        row.extend([
+            self.server_id
+                .as_deref()
+                .map(|id| short_id(id).to_string())
+                .unwrap_or_default(),
+            self.memory_free.map(format_bytes_human).unwrap_or_default(),
+            self.disk_space_free
+                .map(format_bytes_human)
+                .unwrap_or_default(),
+        ]);
+        row
+    }
+
     fn table_detail(&self) -> Vec<(&'static str, String)> {
         let memory = match (self.memory_used, self.memory_total) {
             (Some(used), Some(total)) => {
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::output::display::short_id;
-use crate::resource::Resource;
+use crate::resource::{Identified, Resource};
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Server {
@@ -59,6 +59,29 @@ impl Resource for Server {
         ]
     }
 
+    fn wide_headers() -> Vec<&'static str> {
+        let mut headers = Self::table_headers();
+        headers.extend(["PORT", "ZONE", "MAX USERS"]);
+        headers
+    }
+
+    fn wide_row(&self) -> Vec<String> {
+        let mut row = self.table_row();
+        row.extend([
+            self.connection_port
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            self.zone_id
+                .as_deref()
+                .map(|id| short_id(id).to_string())
+                .unwrap_or_default(),
+            self.max_simultaneous_users
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+        ]);
+        row
+    }
+
     fn table_detail(&self) -> Vec<(&'static str, String)> {
         vec![
             ("SERVER ID", self.server_id.clone()),
@@ -106,3 +129,9 @@ impl Resource for Server {
         ]
     }
 }
+
+impl Identified for Server {
+    fn resource_id(&self) -> &str {
+        &self.server_id
+    }
+}
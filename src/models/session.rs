@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::output::display::{relative_age, short_id};
-use crate::resource::Resource;
+use crate::output::display::{elapsed_since, relative_age, relative_time, short_id};
+use crate::resource::{Identified, Resource};
 
 /// Nested image metadata returned alongside a session.
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
@@ -75,6 +75,32 @@ impl Resource for Session {
         ]
     }
 
+    fn wide_headers() -> Vec<&'static str> {
+        let mut headers = Self::table_headers();
+        headers.extend(["HOST", "SERVER", "IDLE", "EXPIRES"]);
+        headers
+    }
+
+    fn wide_row(&self) -> Vec<String> {
+        let mut row = self.table_row();
+        row.extend([
+            self.hostname.clone().unwrap_or_default(),
+            self.server_id
+                .as_deref()
+                .map(|id| short_id(id).to_string())
+                .unwrap_or_default(),
+            self.keepalive_date
+                .as_deref()
+                .map(elapsed_since)
+                .unwrap_or_default(),
+            self.expiration_date
+                .as_deref()
+                .map(relative_time)
+                .unwrap_or_default(),
+        ]);
+        row
+    }
+
     fn table_detail(&self) -> Vec<(&'static str, String)> {
         let image_friendly = self
             .image
@@ -109,6 +135,91 @@ impl Resource for Session {
     }
 }
 
+impl Identified for Session {
+    fn resource_id(&self) -> &str {
+        &self.kasm_id
+    }
+}
+
+/// A user watching a shared session.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionViewer {
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+}
+
+/// Sharing state of a session, as printed by `share session`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SessionShare {
+    pub share_id: String,
+    pub kasm_id: String,
+    pub share_url: String,
+    #[serde(default)]
+    pub viewers: Vec<SessionViewer>,
+}
+
+impl Resource for SessionShare {
+    fn resource_name() -> &'static str {
+        "Share"
+    }
+
+    fn table_headers() -> Vec<&'static str> {
+        vec!["SHARE ID", "KASM ID", "SHARE LINK", "VIEWERS"]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        let viewers = self
+            .viewers
+            .iter()
+            .map(|v| {
+                v.username
+                    .as_deref()
+                    .or(v.user_id.as_deref())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        vec![
+            self.share_id.clone(),
+            self.kasm_id.clone(),
+            self.share_url.clone(),
+            viewers,
+        ]
+    }
+}
+
+/// Image data returned by the `get_kasm_screenshot` endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Screenshot {
+    pub data: Vec<u8>,
+}
+
+impl Screenshot {
+    /// File extension matching the image format: `png` for PNG data, `jpg` otherwise.
+    pub fn extension(&self) -> &'static str {
+        if self.data.starts_with(b"\x89PNG") {
+            "png"
+        } else {
+            "jpg"
+        }
+    }
+}
+
+/// Response from the `join_kasm` endpoint.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct JoinSessionResponse {
+    pub kasm_id: String,
+    #[serde(default)]
+    pub share_id: Option<String>,
+    #[serde(default)]
+    pub kasm_url: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+}
+
 /// Response from the `request_kasm` endpoint.
 #[derive(PartialEq, Serialize, Deserialize)]
 pub struct CreateSessionResponse {
@@ -169,4 +280,37 @@ mod tests {
             "token value must not appear in JSON output"
         );
     }
+
+    #[test]
+    fn session_share_lists_viewers_by_username() {
+        let share = SessionShare {
+            share_id: "sh-1".into(),
+            kasm_id: "abc123".into(),
+            share_url: "https://kasm.example.com/#/join/sh-1".into(),
+            viewers: vec![
+                SessionViewer {
+                    user_id: Some("u-1".into()),
+                    username: Some("alice".into()),
+                },
+                SessionViewer {
+                    user_id: Some("u-2".into()),
+                    username: None,
+                },
+            ],
+        };
+        assert_eq!(share.table_row()[3], "alice, u-2");
+        assert_eq!(share.object_name(), "share/sh-1");
+    }
+
+    #[test]
+    fn screenshot_extension_follows_image_format() {
+        let png = Screenshot {
+            data: b"\x89PNG\r\n\x1a\n".to_vec(),
+        };
+        assert_eq!(png.extension(), "png");
+        let jpeg = Screenshot {
+            data: vec![0xff, 0xd8, 0xff, 0xe0],
+        };
+        assert_eq!(jpeg.extension(), "jpg");
+    }
 }
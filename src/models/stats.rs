@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+
+use crate::output::display::short_id;
+use crate::resource::{Identified, Resource};
+
+/// How often a session's encoder was held back by the agent's CPU or by the
+/// network, returned by `get_kasm_bottleneck_stats`.
+///
+/// Each value is `[average, maximum]` in percent over the recent frames.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BottleneckStats {
+    #[serde(default)]
+    pub cpu: Vec<f64>,
+    #[serde(default)]
+    pub network: Vec<f64>,
+}
+
+/// Size and timing of the last frame sent by the server.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameInfo {
+    #[serde(default)]
+    pub resx: Option<u32>,
+    #[serde(default)]
+    pub resy: Option<u32>,
+    /// Share of the screen that changed, in percent.
+    #[serde(default)]
+    pub changed: Option<f64>,
+    /// Time the server spent on the frame, in milliseconds.
+    #[serde(default)]
+    pub server_time: Option<f64>,
+}
+
+/// Time one processing step took, in milliseconds.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessTime {
+    pub process_name: String,
+    #[serde(default)]
+    pub time: Option<f64>,
+}
+
+/// Frame timings reported by one connected browser.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientFrameStats {
+    #[serde(default)]
+    pub client: Option<String>,
+    /// Time the client spent decoding and drawing the frame, in milliseconds.
+    #[serde(default)]
+    pub client_time: Option<f64>,
+    /// Round-trip time to the client, in milliseconds.
+    #[serde(default)]
+    pub ping: Option<f64>,
+    #[serde(default)]
+    pub processes: Vec<ProcessTime>,
+}
+
+/// Frame statistics returned by `get_kasm_frame_stats`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameStats {
+    #[serde(default)]
+    pub frame: Option<FrameInfo>,
+    #[serde(default)]
+    pub server_side: Vec<ProcessTime>,
+    #[serde(default)]
+    pub client_side: Vec<ClientFrameStats>,
+}
+
+impl FrameStats {
+    /// Total encoding time of the last frame, falling back to the whole
+    /// server-side frame time when no encoding step is reported.
+    pub fn encode_time(&self) -> Option<f64> {
+        self.server_side
+            .iter()
+            .find(|p| p.process_name.eq_ignore_ascii_case("encoding_total"))
+            .and_then(|p| p.time)
+            .or_else(|| self.frame.as_ref()?.server_time)
+    }
+}
+
+/// Frame and bottleneck statistics of one session, as shown by `stats session`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionStats {
+    pub kasm_id: String,
+    pub bottleneck: BottleneckStats,
+    pub frame: FrameStats,
+}
+
+impl SessionStats {
+    fn resolution(&self) -> String {
+        match self.frame.frame.as_ref().map(|f| (f.resx, f.resy)) {
+            Some((Some(x), Some(y))) => format!("{x}x{y}"),
+            _ => String::new(),
+        }
+    }
+}
+
+fn percent(values: &[f64]) -> String {
+    match values {
+        [avg, max, ..] => format!("{avg}% (max {max}%)"),
+        [avg] => format!("{avg}%"),
+        [] => String::new(),
+    }
+}
+
+fn millis(value: Option<f64>) -> String {
+    value.map(|v| format!("{v}ms")).unwrap_or_default()
+}
+
+impl Resource for SessionStats {
+    fn resource_name() -> &'static str {
+        "SessionStats"
+    }
+
+    fn table_headers() -> Vec<&'static str> {
+        vec![
+            "KASM ID",
+            "CPU",
+            "NETWORK",
+            "ENCODE",
+            "RESOLUTION",
+            "CLIENT",
+            "PING",
+        ]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        let client = self.frame.client_side.first();
+        vec![
+            short_id(&self.kasm_id).to_string(),
+            percent(&self.bottleneck.cpu),
+            percent(&self.bottleneck.network),
+            millis(self.frame.encode_time()),
+            self.resolution(),
+            millis(client.and_then(|c| c.client_time)),
+            millis(client.and_then(|c| c.ping)),
+        ]
+    }
+
+    fn table_detail(&self) -> Vec<(&'static str, String)> {
+        let frame = self.frame.frame.clone().unwrap_or_default();
+        let server_times = self
+            .frame
+            .server_side
+            .iter()
+            .map(|p| format!("{} {}", p.process_name, millis(p.time)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let clients = self
+            .frame
+            .client_side
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}: frame {}, ping {}",
+                    c.client.as_deref().unwrap_or("unknown"),
+                    millis(c.client_time),
+                    millis(c.ping)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        vec![
+            ("KASM ID", self.kasm_id.clone()),
+            ("CPU BOTTLENECK", percent(&self.bottleneck.cpu)),
+            ("NETWORK BOTTLENECK", percent(&self.bottleneck.network)),
+            ("RESOLUTION", self.resolution()),
+            (
+                "CHANGED",
+                frame.changed.map(|c| format!("{c}%")).unwrap_or_default(),
+            ),
+            ("SERVER TIME", millis(frame.server_time)),
+            ("ENCODE TIME", millis(self.frame.encode_time())),
+            ("SERVER STEPS", server_times),
+            ("CLIENTS", clients),
+        ]
+    }
+}
+
+impl Identified for SessionStats {
+    fn resource_id(&self) -> &str {
+        &self.kasm_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> SessionStats {
+        serde_json::from_str(
+            r#"{
+                "kasm_id": "0f3c5a7e-1111",
+                "bottleneck": {"cpu": [12.5, 40], "network": [0, 3]},
+                "frame": {
+                    "frame": {"resx": 1920, "resy": 1080, "changed": 25, "server_time": 30},
+                    "server_side": [
+                        {"process_name": "Analysis", "time": 2},
+                        {"process_name": "Encoding_total", "time": 16}
+                    ],
+                    "client_side": [{"client": "10.0.0.8", "client_time": 5, "ping": 21}]
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn table_row_summarises_bottlenecks_and_timings() {
+        assert_eq!(
+            stats().table_row(),
+            vec![
+                "0f3c5a7e",
+                "12.5% (max 40%)",
+                "0% (max 3%)",
+                "16ms",
+                "1920x1080",
+                "5ms",
+                "21ms"
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_time_falls_back_to_server_time() {
+        let mut stats = stats();
+        stats.frame.server_side.clear();
+        assert_eq!(stats.frame.encode_time(), Some(30.0));
+
+        let empty = SessionStats::default();
+        assert!(empty.table_row()[1..].iter().all(String::is_empty));
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::output::display::short_id;
+use crate::resource::{Identified, Resource};
+
+/// Group membership entry returned alongside a user.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserGroup {
+    #[serde(default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub user_id: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub organization: Option<String>,
+    #[serde(default)]
+    pub realm: Option<String>,
+    #[serde(default)]
+    pub locked: Option<bool>,
+    #[serde(default)]
+    pub disabled: Option<bool>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub last_session: Option<String>,
+    #[serde(default)]
+    pub groups: Option<Vec<UserGroup>>,
+}
+
+impl User {
+    /// First and last name joined with a space, skipping whichever is missing.
+    pub fn full_name(&self) -> String {
+        [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Resource for User {
+    fn resource_name() -> &'static str {
+        "User"
+    }
+
+    fn table_headers() -> Vec<&'static str> {
+        vec![
+            "USER ID",
+            "USERNAME",
+            "NAME",
+            "REALM",
+            "LOCKED",
+            "DISABLED",
+            "LAST SESSION",
+        ]
+    }
+
+    fn table_row(&self) -> Vec<String> {
+        vec![
+            short_id(&self.user_id).to_string(),
+            self.username.clone().unwrap_or_default(),
+            self.full_name(),
+            self.realm.clone().unwrap_or_default(),
+            self.locked.map(|v| v.to_string()).unwrap_or_default(),
+            self.disabled.map(|v| v.to_string()).unwrap_or_default(),
+            self.last_session.clone().unwrap_or_default(),
+        ]
+    }
+
+    fn table_detail(&self) -> Vec<(&'static str, String)> {
+        let groups = self
+            .groups
+            .as_ref()
+            .map(|groups| {
+                groups
+                    .iter()
+                    .filter_map(|g| g.name.as_deref().or(g.group_id.as_deref()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+
+        vec![
+            ("USER ID", self.user_id.clone()),
+            ("USERNAME", self.username.clone().unwrap_or_default()),
+            ("FIRST NAME", self.first_name.clone().unwrap_or_default()),
+            ("LAST NAME", self.last_name.clone().unwrap_or_default()),
+            ("PHONE", self.phone.clone().unwrap_or_default()),
+            (
+                "ORGANIZATION",
+                self.organization.clone().unwrap_or_default(),
+            ),
+            ("REALM", self.realm.clone().unwrap_or_default()),
+            (
+                "LOCKED",
+                self.locked.map(|v| v.to_string()).unwrap_or_default(),
+            ),
+            (
+                "DISABLED",
+                self.disabled.map(|v| v.to_string()).unwrap_or_default(),
+            ),
+            ("GROUPS", groups),
+            ("CREATED", self.created.clone().unwrap_or_default()),
+            (
+                "LAST SESSION",
+                self.last_session.clone().unwrap_or_default(),
+            ),
+        ]
+    }
+}
+
+impl Identified for User {
+    fn resource_id(&self) -> &str {
+        &self.user_id
+    }
+}
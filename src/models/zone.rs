@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::output::display::short_id;
-use crate::resource::Resource;
+use crate::resource::{Identified, Resource};
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Zone {
@@ -48,6 +48,22 @@ impl Resource for Zone {
         ]
     }
 
+    fn wide_headers() -> Vec<&'static str> {
+        let mut headers = Self::table_headers();
+        headers.extend(["PROXY HOSTNAME", "PROXY PORT", "ORIGIN DOMAIN"]);
+        headers
+    }
+
+    fn wide_row(&self) -> Vec<String> {
+        let mut row = self.table_row();
+        row.extend([
+            self.proxy_hostname.clone().unwrap_or_default(),
+            self.proxy_port.map(|v| v.to_string()).unwrap_or_default(),
+            self.allow_origin_domain.clone().unwrap_or_default(),
+        ]);
+        row
+    }
+
     fn table_detail(&self) -> Vec<(&'static str, String)> {
         vec![
             ("ZONE ID", self.zone_id.clone()),
@@ -94,3 +110,9 @@ impl Resource for Zone {
         ]
     }
 }
+
+impl Identified for Zone {
+    fn resource_id(&self) -> &str {
+        &self.zone_id
+    }
+}
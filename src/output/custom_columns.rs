@@ -0,0 +1,131 @@
+//! Whitespace-aligned columns for `-o custom-columns=HEADER:PATH,...`.
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::jsonpath::JsonPath;
+
+/// Placeholder for a column whose path matched nothing.
+const NONE: &str = "<none>";
+
+/// One `HEADER:PATH` entry of a custom-columns spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub header: String,
+    pub path: JsonPath,
+}
+
+/// Parse a spec such as `ID:.kasm_id,HOST:.hostname`. Paths may also be
+/// written with braces, e.g. `ID:{.kasm_id}`.
+pub fn parse_columns(spec: &str) -> Result<Vec<Column>, String> {
+    spec.split(',')
+        .map(|entry| {
+            let (header, path) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("invalid custom column {entry:?}: expected HEADER:PATH"))?;
+            let header = header.trim();
+            let path = path.trim();
+            if header.is_empty() || path.is_empty() {
+                return Err(format!(
+                    "invalid custom column {entry:?}: expected HEADER:PATH"
+                ));
+            }
+            let template = if path.starts_with('{') {
+                path.to_string()
+            } else {
+                format!("{{{path}}}")
+            };
+            Ok(Column {
+                header: header.to_string(),
+                path: JsonPath::parse(&template)?,
+            })
+        })
+        .collect()
+}
+
+pub fn render_list<T: Serialize>(items: &[T], columns: &[Column]) -> Result<String> {
+    let values = items
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(render_rows(&values, columns))
+}
+
+pub fn render_one<T: Serialize>(item: &T, columns: &[Column]) -> Result<String> {
+    Ok(render_rows(&[serde_json::to_value(item)?], columns))
+}
+
+fn render_rows(values: &[Value], columns: &[Column]) -> String {
+    let mut rows = vec![columns.iter().map(|c| c.header.clone()).collect::<Vec<_>>()];
+    for value in values {
+        rows.push(
+            columns
+                .iter()
+                .map(|c| {
+                    let cell = c.path.render_value(value);
+                    if cell.is_empty() {
+                        NONE.to_string()
+                    } else {
+                        cell
+                    }
+                })
+                .collect(),
+        );
+    }
+
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0))
+        .collect();
+    rows.iter()
+        .map(|row| {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            line.join("   ").trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_paths_with_and_without_braces() {
+        let columns = parse_columns("ID:.kasm_id, HOST:{.hostname}").unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].header, "ID");
+        assert_eq!(columns[1].header, "HOST");
+        assert_eq!(columns[1].path.render_value(&json!({"hostname": "h"})), "h");
+    }
+
+    #[test]
+    fn rejects_entries_without_header_or_path() {
+        assert!(parse_columns("ID").is_err());
+        assert!(parse_columns(":.kasm_id").is_err());
+        assert!(parse_columns("ID:").is_err());
+        assert!(parse_columns("ID:kasm_id").is_err());
+    }
+
+    #[test]
+    fn aligns_columns_and_marks_missing_values() {
+        let columns = parse_columns("ID:.id,SHARE:.share_id").unwrap();
+        let out = render_list(
+            &[
+                json!({"id": "a", "share_id": "s-long-value"}),
+                json!({"id": "bbbbbb"}),
+            ],
+            &columns,
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "ID       SHARE\na        s-long-value\nbbbbbb   <none>"
+        );
+    }
+}
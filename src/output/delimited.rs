@@ -0,0 +1,147 @@
+//! CSV and TSV output for spreadsheets and reports.
+//!
+//! By default the columns are the same as the table view. With `--all-fields`
+//! every serialized field becomes a column instead; nested objects are
+//! flattened into dotted column names such as `image.friendly_name`.
+//!
+//! Fields containing the separator, a double quote, or a line break are quoted
+//! with embedded quotes doubled, as spreadsheet applications expect for both
+//! CSV and TSV.
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::resource::Resource;
+
+pub fn render_list<R: Resource>(items: &[R], separator: char, all_fields: bool) -> Result<String> {
+    if all_fields {
+        return render_fields(items, separator);
+    }
+    let mut records = vec![record(R::table_headers(), separator)];
+    records.extend(items.iter().map(|item| record(item.table_row(), separator)));
+    Ok(records.join("\n"))
+}
+
+pub fn render_one<R: Resource>(item: &R, separator: char, all_fields: bool) -> Result<String> {
+    render_list(std::slice::from_ref(item), separator, all_fields)
+}
+
+/// Render every serialized field of `items`, in order of first appearance.
+pub fn render_fields<T: Serialize>(items: &[T], separator: char) -> Result<String> {
+    let rows = items
+        .iter()
+        .map(|item| {
+            let mut row = Vec::new();
+            flatten("", serde_json::to_value(item)?, &mut row);
+            Ok(row)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut columns: Vec<&str> = Vec::new();
+    for (key, _) in rows.iter().flatten() {
+        if !columns.contains(&key.as_str()) {
+            columns.push(key);
+        }
+    }
+    if columns.is_empty() {
+        return Ok(String::new());
+    }
+
+    let mut records = vec![record(columns.iter().copied(), separator)];
+    for row in &rows {
+        let cells = columns.iter().map(|column| {
+            row.iter()
+                .find(|(key, _)| key == column)
+                .map(|(_, value)| value.as_str())
+                .unwrap_or_default()
+        });
+        records.push(record(cells, separator));
+    }
+    Ok(records.join("\n"))
+}
+
+/// Flatten a JSON value into `(column, cell)` pairs. Objects recurse with dotted
+/// keys; arrays and scalars become a single cell.
+fn flatten(prefix: &str, value: Value, out: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => flatten_object(prefix, map, out),
+        Value::Object(_) | Value::Null => out.push((prefix.to_string(), String::new())),
+        Value::String(s) => out.push((prefix.to_string(), s)),
+        other => out.push((prefix.to_string(), other.to_string())),
+    }
+}
+
+fn flatten_object(prefix: &str, map: Map<String, Value>, out: &mut Vec<(String, String)>) {
+    for (key, value) in map {
+        let key = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}.{key}")
+        };
+        flatten(&key, value, out);
+    }
+}
+
+/// Join one record's fields with `separator`, quoting where needed.
+fn record<I, S>(fields: I, separator: char) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    fields
+        .into_iter()
+        .map(|field| quote(field.as_ref(), separator))
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+fn quote(field: &str, separator: char) -> String {
+    if field.contains([separator, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn quotes_separators_quotes_and_newlines() {
+        let out = record(
+            ["plain", "a,b", "say \"hi\"", "two\nlines", "tab\there"],
+            ',',
+        );
+        assert_eq!(
+            out,
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\",tab\there"
+        );
+    }
+
+    #[test]
+    fn tsv_quotes_tabs_but_not_commas() {
+        let out = record(["a,b", "tab\there"], '\t');
+        assert_eq!(out, "a,b\t\"tab\there\"");
+    }
+
+    #[test]
+    fn all_fields_flattens_nested_objects_and_unions_columns() {
+        let items = [
+            json!({"id": "a", "image": {"name": "term"}, "tags": ["x", "y"]}),
+            json!({"id": "b", "extra": null, "image": null}),
+        ];
+        let out = render_fields(&items, ',').unwrap();
+        assert_eq!(
+            out,
+            "id,image.name,tags,extra,image\na,term,\"[\"\"x\"\",\"\"y\"\"]\",,\nb,,,,"
+        );
+    }
+
+    #[test]
+    fn all_fields_of_nothing_is_empty() {
+        assert_eq!(render_fields::<Value>(&[], ',').unwrap(), "");
+    }
+}
@@ -34,18 +34,44 @@ pub fn relative_age(datetime: &str) -> String {
         return datetime.to_string();
     };
 
-    let now = SystemTime::now()
+    let diff = now_epoch().saturating_sub(epoch);
+    format_duration_ago(diff)
+}
+
+/// Like [`relative_age`] but without the `" ago"` suffix (e.g. `"2h"`), for
+/// columns such as idle time. Falls back to the original string if parsing fails.
+pub fn elapsed_since(datetime: &str) -> String {
+    let Some(epoch) = parse_datetime_to_epoch(datetime) else {
+        return datetime.to_string();
+    };
+    format_duration(now_epoch().saturating_sub(epoch))
+}
+
+/// Human-friendly distance to a datetime in either direction: `"in 2h"` for
+/// future times and `"3d ago"` for past ones.
+/// Falls back to returning the original string if parsing fails.
+pub fn relative_time(datetime: &str) -> String {
+    let Some(epoch) = parse_datetime_to_epoch(datetime) else {
+        return datetime.to_string();
+    };
+    let now = now_epoch();
+    if epoch > now {
+        format!("in {}", format_duration(epoch - now))
+    } else {
+        format_duration_ago(now - epoch)
+    }
+}
+
+fn now_epoch() -> u64 {
+    SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or(Duration::ZERO)
-        .as_secs();
-
-    let diff = now.saturating_sub(epoch);
-    format_duration_ago(diff)
+        .as_secs()
 }
 
 /// Convert a `"YYYY-MM-DD HH:MM:SS"` UTC datetime string to seconds since the UNIX epoch.
 /// Returns `None` if the string cannot be parsed.
-fn parse_datetime_to_epoch(s: &str) -> Option<u64> {
+pub(crate) fn parse_datetime_to_epoch(s: &str) -> Option<u64> {
     let b = s.as_bytes();
     if b.len() != 19 {
         return None;
@@ -90,14 +116,19 @@ fn parse_datetime_to_epoch(s: &str) -> Option<u64> {
 
 /// Format a duration in seconds as a human-friendly "X unit ago" string.
 fn format_duration_ago(secs: u64) -> String {
+    format!("{} ago", format_duration(secs))
+}
+
+/// Format a duration in seconds using its largest unit, e.g. `"45s"`, `"5m"`, `"2h"`, `"3d"`.
+fn format_duration(secs: u64) -> String {
     if secs < 60 {
-        format!("{}s ago", secs)
+        format!("{}s", secs)
     } else if secs < 3600 {
-        format!("{}m ago", secs / 60)
+        format!("{}m", secs / 60)
     } else if secs < 86400 {
-        format!("{}h ago", secs / 3600)
+        format!("{}h", secs / 3600)
     } else {
-        format!("{}d ago", secs / 86400)
+        format!("{}d", secs / 86400)
     }
 }
 
@@ -183,4 +214,26 @@ mod tests {
         let bad = "not-a-datetime";
         assert_eq!(relative_age(bad), bad);
     }
+
+    // --- elapsed_since / relative_time ---
+
+    #[test]
+    fn elapsed_since_has_no_suffix() {
+        assert_eq!(
+            elapsed_since("2000-01-01 00:00:00").chars().last(),
+            Some('d')
+        );
+        assert_eq!(elapsed_since("not-a-datetime"), "not-a-datetime");
+    }
+
+    #[test]
+    fn relative_time_handles_past_and_future() {
+        assert!(relative_time("2000-01-01 00:00:00").ends_with("d ago"));
+        let future = relative_time("2999-01-01 00:00:00");
+        assert!(
+            future.starts_with("in ") && future.ends_with('d'),
+            "{future}"
+        );
+        assert_eq!(relative_time("not-a-datetime"), "not-a-datetime");
+    }
 }
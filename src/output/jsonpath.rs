@@ -0,0 +1,321 @@
+//! A kubectl-style JSONPath subset for `-o jsonpath=...` and `-o custom-columns=...`.
+//!
+//! Templates mix literal text with `{...}` expressions evaluated against the
+//! serde JSON form of a resource:
+//!
+//! - `{.field}`, `{.a.b}`, `{['field']}` — object fields
+//! - `{.list[0]}`, `{.list[*]}` — array elements
+//! - `{.}` or `{@}` — the current object
+//! - `{"\n"}` — a string literal (supports `\n`, `\t`, `\"`, `\\`)
+//! - `{range .list[*]}...{end}` — repeat the body for each result
+//!
+//! Missing fields produce no output. Multiple results are joined with spaces.
+
+use std::fmt;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A parsed JSONPath template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    source: String,
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Path(Vec<Segment>),
+    Range(Vec<Segment>, Vec<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    Wildcard,
+}
+
+impl JsonPath {
+    /// Parse a template such as `{.kasm_id}{"\n"}`.
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut stack: Vec<(Vec<Segment>, Vec<Node>)> = Vec::new();
+        let mut nodes = Vec::new();
+        let mut rest = template;
+
+        while !rest.is_empty() {
+            let Some(start) = rest.find('{') else {
+                nodes.push(Node::Text(rest.to_string()));
+                break;
+            };
+            if start > 0 {
+                nodes.push(Node::Text(rest[..start].to_string()));
+            }
+            let end = find_closing_brace(&rest[start..])
+                .ok_or_else(|| format!("unclosed '{{' in jsonpath template {template:?}"))?;
+            let expr = rest[start + 1..start + end].trim();
+            rest = &rest[start + end + 1..];
+
+            if expr == "end" {
+                let (path, outer) = stack
+                    .pop()
+                    .ok_or_else(|| "{end} without a matching {range}".to_string())?;
+                let body = std::mem::replace(&mut nodes, outer);
+                nodes.push(Node::Range(path, body));
+            } else if let Some(path) = expr.strip_prefix("range ") {
+                let path = parse_path(path.trim())?;
+                stack.push((path, std::mem::take(&mut nodes)));
+            } else if expr.starts_with('"') {
+                nodes.push(Node::Text(parse_string_literal(expr)?));
+            } else {
+                nodes.push(Node::Path(parse_path(expr)?));
+            }
+        }
+
+        if !stack.is_empty() {
+            return Err("{range} without a matching {end}".to_string());
+        }
+        if nodes.is_empty() {
+            return Err("jsonpath template must not be empty".to_string());
+        }
+        Ok(Self {
+            source: template.to_string(),
+            nodes,
+        })
+    }
+
+    /// Evaluate the template against the JSON form of `value`.
+    pub fn render<T: Serialize>(&self, value: &T) -> Result<String> {
+        let value = serde_json::to_value(value)?;
+        let mut out = String::new();
+        render_nodes(&self.nodes, &value, &mut out);
+        Ok(out)
+    }
+
+    /// Evaluate the template against an already-serialized value.
+    pub fn render_value(&self, value: &Value) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, value, &mut out);
+        out
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Return the byte offset of the `}` closing the `{` at the start of `s`,
+/// skipping braces inside string literals.
+fn find_closing_brace(s: &str) -> Option<usize> {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '}' if !in_string => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_string_literal(expr: &str) -> Result<String, String> {
+    let inner = expr
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| format!("invalid string literal {expr}"))?;
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some(other @ ('"' | '\\')) => out.push(other),
+            _ => return Err(format!("invalid escape in string literal {expr}")),
+        }
+    }
+    Ok(out)
+}
+
+fn parse_path(expr: &str) -> Result<Vec<Segment>, String> {
+    let invalid = || format!("invalid jsonpath expression {expr:?}");
+    let mut rest = expr
+        .strip_prefix('$')
+        .or_else(|| expr.strip_prefix('@'))
+        .unwrap_or(expr);
+    if rest.is_empty() || rest == "." {
+        return Ok(Vec::new());
+    }
+    if !rest.starts_with(['.', '[']) {
+        return Err(invalid());
+    }
+
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let len = after
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(after.len());
+            match &after[..len] {
+                "" if after.starts_with('*') => {
+                    segments.push(Segment::Wildcard);
+                    rest = &after[1..];
+                }
+                "" => return Err(invalid()),
+                name => {
+                    segments.push(Segment::Field(name.to_string()));
+                    rest = &after[len..];
+                }
+            }
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let close = after.find(']').ok_or_else(invalid)?;
+            let inner = after[..close].trim();
+            let segment = if inner == "*" {
+                Segment::Wildcard
+            } else if let Some(name) = inner
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+            {
+                Segment::Field(name.to_string())
+            } else {
+                Segment::Index(inner.parse().map_err(|_| invalid())?)
+            };
+            segments.push(segment);
+            rest = &after[close + 1..];
+        } else {
+            return Err(invalid());
+        }
+    }
+    Ok(segments)
+}
+
+/// Resolve `path` against `root`, fanning out on wildcards.
+fn evaluate<'a>(path: &[Segment], root: &'a Value) -> Vec<&'a Value> {
+    let mut current = vec![root];
+    for segment in path {
+        current = current
+            .into_iter()
+            .flat_map(|value| -> Vec<&Value> {
+                match (segment, value) {
+                    (Segment::Field(name), Value::Object(map)) => {
+                        map.get(name).into_iter().collect()
+                    }
+                    (Segment::Index(i), Value::Array(items)) => items.get(*i).into_iter().collect(),
+                    (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+                    (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                    _ => Vec::new(),
+                }
+            })
+            .collect();
+    }
+    current
+}
+
+fn render_nodes(nodes: &[Node], value: &Value, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Path(path) => {
+                let results: Vec<String> = evaluate(path, value)
+                    .into_iter()
+                    .filter(|v| !v.is_null())
+                    .map(scalar_string)
+                    .collect();
+                out.push_str(&results.join(" "));
+            }
+            Node::Range(path, body) => {
+                for item in evaluate(path, value) {
+                    render_nodes(body, item, out);
+                }
+            }
+        }
+    }
+}
+
+/// Strings print raw; everything else prints as compact JSON.
+fn scalar_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(template: &str, value: Value) -> String {
+        JsonPath::parse(template).unwrap().render_value(&value)
+    }
+
+    #[test]
+    fn renders_fields_and_literal_text() {
+        let value = json!({"kasm_id": "abc", "hostname": "agent-1", "cores": 2.5});
+        assert_eq!(render("{.kasm_id}", value.clone()), "abc");
+        assert_eq!(
+            render("id={.kasm_id} host={.hostname}", value.clone()),
+            "id=abc host=agent-1"
+        );
+        assert_eq!(render("{.cores}", value.clone()), "2.5");
+        assert_eq!(render("{['kasm_id']}", value), "abc");
+    }
+
+    #[test]
+    fn renders_nested_fields_indexes_and_wildcards() {
+        let value = json!({
+            "image": {"friendly_name": "Terminal"},
+            "groups": [{"name": "Admins"}, {"name": "Users"}],
+        });
+        assert_eq!(render("{.image.friendly_name}", value.clone()), "Terminal");
+        assert_eq!(render("{.groups[1].name}", value.clone()), "Users");
+        assert_eq!(render("{.groups[*].name}", value), "Admins Users");
+    }
+
+    #[test]
+    fn missing_and_null_fields_render_empty() {
+        let value = json!({"share_id": null});
+        assert_eq!(render("[{.share_id}]", value.clone()), "[]");
+        assert_eq!(render("[{.nope.deeper}]", value.clone()), "[]");
+        assert_eq!(render("[{.list[3]}]", value), "[]");
+    }
+
+    #[test]
+    fn string_literals_and_range() {
+        let value = json!({"groups": [{"name": "Admins"}, {"name": "Users"}]});
+        assert_eq!(
+            render(r#"{range .groups[*]}{.name}{"\n"}{end}"#, value),
+            "Admins\nUsers\n"
+        );
+        assert_eq!(render(r#"{"a}b"}"#, json!({})), "a}b");
+    }
+
+    #[test]
+    fn current_object_renders_as_json() {
+        assert_eq!(render("{.}", json!({"a": 1})), r#"{"a":1}"#);
+        assert_eq!(render("{@}", json!("plain")), "plain");
+    }
+
+    #[test]
+    fn parse_rejects_malformed_templates() {
+        assert!(JsonPath::parse("").is_err());
+        assert!(JsonPath::parse("{.kasm_id").is_err());
+        assert!(JsonPath::parse("{kasm_id}").is_err());
+        assert!(JsonPath::parse("{.list[x]}").is_err());
+        assert!(JsonPath::parse("{range .items[*]}{.name}").is_err());
+        assert!(JsonPath::parse("{end}").is_err());
+        assert!(JsonPath::parse(r#"{"\q"}"#).is_err());
+    }
+}
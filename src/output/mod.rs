@@ -1,33 +1,133 @@
+pub mod custom_columns;
+pub mod delimited;
 pub mod display;
 pub mod json;
+pub mod jsonpath;
+pub mod name;
 pub mod table;
 pub mod yaml;
 
+use std::str::FromStr;
+
 use anyhow::Result;
-use clap::ValueEnum;
+use serde::Serialize;
 
+use self::custom_columns::Column;
+use self::jsonpath::JsonPath;
 use crate::resource::Resource;
 
-#[derive(Clone, Default, ValueEnum)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum OutputFormat {
     #[default]
     Table,
+    /// Table with extra per-resource columns.
+    Wide,
     Json,
     Yaml,
+    /// Comma-separated values; `all_fields` uses every serialized field as a column.
+    Csv {
+        all_fields: bool,
+    },
+    /// Tab-separated values; `all_fields` as for `Csv`.
+    Tsv {
+        all_fields: bool,
+    },
+    /// `<kind>/<id>` per resource.
+    Name,
+    /// `jsonpath=<template>`, evaluated against each resource's JSON form.
+    JsonPath(JsonPath),
+    /// `custom-columns=<HEADER:PATH,...>`.
+    CustomColumns(Vec<Column>),
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(OutputFormat::Table),
+            "wide" => Ok(OutputFormat::Wide),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "csv" => Ok(OutputFormat::Csv { all_fields: false }),
+            "tsv" => Ok(OutputFormat::Tsv { all_fields: false }),
+            "name" => Ok(OutputFormat::Name),
+            _ => {
+                if let Some(template) = s.strip_prefix("jsonpath=") {
+                    JsonPath::parse(template).map(OutputFormat::JsonPath)
+                } else if let Some(spec) = s.strip_prefix("custom-columns=") {
+                    custom_columns::parse_columns(spec).map(OutputFormat::CustomColumns)
+                } else {
+                    Err(format!(
+                        "unknown output format {s:?} (expected table, wide, json, yaml, csv, tsv, name, \
+                         jsonpath=<TEMPLATE>, or custom-columns=<SPEC>)"
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Apply `--all-fields`, which is only meaningful for `csv` and `tsv`.
+    pub fn with_all_fields(self) -> Result<Self, String> {
+        match self {
+            OutputFormat::Csv { .. } => Ok(OutputFormat::Csv { all_fields: true }),
+            OutputFormat::Tsv { .. } => Ok(OutputFormat::Tsv { all_fields: true }),
+            _ => Err("--all-fields requires -o csv or -o tsv".to_string()),
+        }
+    }
+
+    /// True for the human-readable table formats (`table` and `wide`).
+    pub fn is_table(&self) -> bool {
+        matches!(self, OutputFormat::Table | OutputFormat::Wide)
+    }
 }
 
 pub fn render_list<R: Resource>(items: &[R], format: &OutputFormat) -> Result<String> {
     match format {
         OutputFormat::Table => table::render_list(items),
+        OutputFormat::Wide => table::render_list_wide(items),
         OutputFormat::Json => json::render_list(items),
         OutputFormat::Yaml => yaml::render_list(items),
+        OutputFormat::Csv { all_fields } => delimited::render_list(items, ',', *all_fields),
+        OutputFormat::Tsv { all_fields } => delimited::render_list(items, '\t', *all_fields),
+        OutputFormat::Name => name::render_list(items),
+        OutputFormat::JsonPath(path) => Ok(items
+            .iter()
+            .map(|item| path.render(item))
+            .collect::<Result<Vec<_>>>()?
+            .join("\n")),
+        OutputFormat::CustomColumns(columns) => custom_columns::render_list(items, columns),
     }
 }
 
 pub fn render_one<R: Resource>(item: &R, format: &OutputFormat) -> Result<String> {
     match format {
-        OutputFormat::Table => table::render_one(item),
+        OutputFormat::Table | OutputFormat::Wide => table::render_one(item),
         OutputFormat::Json => json::render_one(item),
         OutputFormat::Yaml => yaml::render_one(item),
+        OutputFormat::Csv { all_fields } => delimited::render_one(item, ',', *all_fields),
+        OutputFormat::Tsv { all_fields } => delimited::render_one(item, '\t', *all_fields),
+        OutputFormat::Name => name::render_one(item),
+        OutputFormat::JsonPath(path) => path.render(item),
+        OutputFormat::CustomColumns(columns) => custom_columns::render_one(item, columns),
+    }
+}
+
+/// Render a command result that is not a [`Resource`] (e.g. `health`, `top`)
+/// in a structured format. Callers handle `Table` themselves.
+pub fn render_value<T: Serialize>(value: &T, format: &OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => json::render_one(value),
+        OutputFormat::Yaml => yaml::render_one(value),
+        OutputFormat::Csv { .. } => delimited::render_fields(std::slice::from_ref(value), ','),
+        OutputFormat::Tsv { .. } => delimited::render_fields(std::slice::from_ref(value), '\t'),
+        OutputFormat::JsonPath(path) => path.render(value),
+        OutputFormat::CustomColumns(columns) => custom_columns::render_one(value, columns),
+        OutputFormat::Name => anyhow::bail!("-o name is not supported by this command"),
+        OutputFormat::Table | OutputFormat::Wide => {
+            anyhow::bail!("table output must be rendered by the caller")
+        }
     }
 }
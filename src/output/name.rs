@@ -0,0 +1,17 @@
+//! `-o name`: one `<kind>/<id>` line per resource, for piping into other commands.
+
+use anyhow::Result;
+
+use crate::resource::Resource;
+
+pub fn render_list<R: Resource>(items: &[R]) -> Result<String> {
+    Ok(items
+        .iter()
+        .map(Resource::object_name)
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+pub fn render_one<R: Resource>(item: &R) -> Result<String> {
+    Ok(item.object_name())
+}
@@ -4,15 +4,28 @@ use comfy_table::{Table, presets::UTF8_FULL_CONDENSED};
 use crate::resource::Resource;
 
 pub fn render_list<R: Resource>(items: &[R]) -> Result<String> {
+    render_rows(items, R::table_headers(), R::table_row)
+}
+
+/// Like [`render_list`], using the extra columns from `wide_headers()`/`wide_row()`.
+pub fn render_list_wide<R: Resource>(items: &[R]) -> Result<String> {
+    render_rows(items, R::wide_headers(), R::wide_row)
+}
+
+fn render_rows<R: Resource>(
+    items: &[R],
+    headers: Vec<&'static str>,
+    row: fn(&R) -> Vec<String>,
+) -> Result<String> {
     if items.is_empty() {
         return Ok(format!("No {}s found.", R::resource_name().to_lowercase()));
     }
 
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
-    table.set_header(R::table_headers());
+    table.set_header(headers);
     for item in items {
-        table.add_row(item.table_row());
+        table.add_row(row(item));
     }
 
     let plural = if items.len() == 1 { "" } else { "s" };
@@ -5,6 +5,18 @@ pub trait Resource: Sized + Serialize {
     fn table_headers() -> Vec<&'static str>;
     fn table_row(&self) -> Vec<String>;
 
+    /// Headers for `-o wide` list output.
+    /// Default implementation returns `table_headers()`.
+    fn wide_headers() -> Vec<&'static str> {
+        Self::table_headers()
+    }
+
+    /// Row for `-o wide` list output; must line up with `wide_headers()`.
+    /// Default implementation returns `table_row()`.
+    fn wide_row(&self) -> Vec<String> {
+        self.table_row()
+    }
+
     /// Return key-value pairs for a detailed single-resource view.
     /// Default implementation builds pairs from `table_headers()` and `table_row()`.
     fn table_detail(&self) -> Vec<(&'static str, String)> {
@@ -13,4 +25,23 @@ pub trait Resource: Sized + Serialize {
             .zip(self.table_row())
             .collect()
     }
+
+    /// Return the `<kind>/<id>` line printed by `-o name`.
+    /// Default implementation uses the first `table_detail()` value, which is
+    /// the full ID for server-side resources.
+    fn object_name(&self) -> String {
+        let id = self
+            .table_detail()
+            .into_iter()
+            .next()
+            .map(|(_, value)| value)
+            .unwrap_or_default();
+        format!("{}/{id}", Self::resource_name().to_lowercase())
+    }
+}
+
+/// A resource with a stable server-assigned ID, used to track individual
+/// objects across repeated polls (e.g. `get --watch`).
+pub trait Identified: Resource {
+    fn resource_id(&self) -> &str;
 }
@@ -0,0 +1,218 @@
+//! Polling watch loop for `get <resources> --watch` and `stats session --watch`.
+//!
+//! Each poll is compared with the previous one by [`Identified::resource_id`].
+//! Table output redraws the whole list in place on a terminal, while JSON and
+//! YAML output emit one `ADDED`/`MODIFIED`/`DELETED` event per changed object.
+//! Other formats (`name`, `jsonpath`, `custom-columns`) reprint the list on change.
+
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cli::filters::format_utc_minus;
+use crate::output::{self, OutputFormat};
+use crate::resource::Identified;
+
+/// ANSI sequence that moves the cursor home and clears the screen.
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EventType {
+    Added,
+    Modified,
+    Deleted,
+}
+
+/// A change to a single object between two polls.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct WatchEvent<'a, T> {
+    #[serde(rename = "type")]
+    pub event_type: EventType,
+    pub object: &'a T,
+}
+
+/// Compare two snapshots and return the events that turn `previous` into `current`.
+///
+/// Added and modified objects are reported in `current` order, followed by
+/// deleted objects in `previous` order.
+pub fn diff_events<'a, T: Identified + PartialEq>(
+    previous: &'a [T],
+    current: &'a [T],
+) -> Vec<WatchEvent<'a, T>> {
+    let mut events = Vec::new();
+
+    for object in current {
+        match previous
+            .iter()
+            .find(|p| p.resource_id() == object.resource_id())
+        {
+            None => events.push(WatchEvent {
+                event_type: EventType::Added,
+                object,
+            }),
+            Some(old) if old != object => events.push(WatchEvent {
+                event_type: EventType::Modified,
+                object,
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for object in previous {
+        if !current
+            .iter()
+            .any(|c| c.resource_id() == object.resource_id())
+        {
+            events.push(WatchEvent {
+                event_type: EventType::Deleted,
+                object,
+            });
+        }
+    }
+
+    events
+}
+
+/// Render events for streaming output: one compact JSON object per line, or
+/// one YAML document per event.
+pub fn render_events<T: Serialize>(
+    events: &[WatchEvent<'_, T>],
+    format: &OutputFormat,
+) -> Result<String> {
+    let mut out = String::new();
+    for event in events {
+        match format {
+            OutputFormat::Yaml => {
+                out.push_str("---\n");
+                out.push_str(&serde_yaml::to_string(event)?);
+            }
+            _ => {
+                out.push_str(&serde_json::to_string(event)?);
+                out.push('\n');
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Poll `fetch` every `interval` and print changes until interrupted.
+///
+/// A failed first poll is returned as an error; later failures are reported
+/// on stderr and polling continues.
+pub fn run<T, F>(title: &str, interval: Duration, format: &OutputFormat, mut fetch: F) -> Result<()>
+where
+    T: Identified + PartialEq,
+    F: FnMut() -> Result<Vec<T>>,
+{
+    let redraw = format.is_table() && io::stdout().is_terminal();
+    let mut previous: Option<Vec<T>> = None;
+
+    loop {
+        match fetch() {
+            Ok(current) => {
+                let mut stdout = io::stdout().lock();
+                match format {
+                    OutputFormat::Json | OutputFormat::Yaml => {
+                        let events = diff_events(previous.as_deref().unwrap_or_default(), &current);
+                        write!(stdout, "{}", render_events(&events, format)?)?;
+                    }
+                    _ => {
+                        let changed = previous
+                            .as_deref()
+                            .is_none_or(|prev| !diff_events(prev, &current).is_empty());
+                        if redraw {
+                            write!(stdout, "{CLEAR_SCREEN}")?;
+                            writeln!(
+                                stdout,
+                                "Every {interval:?}: {title}    {} UTC\n",
+                                format_utc_minus(0)
+                            )?;
+                            writeln!(stdout, "{}", output::render_list(&current, format)?)?;
+                        } else if changed {
+                            writeln!(stdout, "{}\n", output::render_list(&current, format)?)?;
+                        }
+                    }
+                }
+                stdout.flush()?;
+                previous = Some(current);
+            }
+            Err(e) if previous.is_none() => return Err(e),
+            Err(e) => eprintln!("error: {e:#}"),
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::session::Session;
+
+    fn session(id: &str, status: &str) -> Session {
+        Session {
+            kasm_id: id.into(),
+            operational_status: Some(status.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn first_poll_reports_everything_as_added() {
+        let current = vec![session("a", "running"), session("b", "starting")];
+        let events = diff_events(&[], &current);
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|e| e.event_type == EventType::Added));
+    }
+
+    #[test]
+    fn detects_added_modified_and_deleted() {
+        let previous = vec![session("a", "running"), session("b", "starting")];
+        let current = vec![session("b", "running"), session("c", "starting")];
+        let events = diff_events(&previous, &current);
+        let summary: Vec<(EventType, &str)> = events
+            .iter()
+            .map(|e| (e.event_type, e.object.kasm_id.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (EventType::Modified, "b"),
+                (EventType::Added, "c"),
+                (EventType::Deleted, "a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unchanged_snapshot_has_no_events() {
+        let previous = vec![session("a", "running")];
+        let current = vec![session("a", "running")];
+        assert!(diff_events(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn json_events_are_line_delimited() {
+        let current = vec![session("a", "running"), session("b", "running")];
+        let events = diff_events(&[], &current);
+        let out = render_events(&events, &OutputFormat::Json).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["type"], "ADDED");
+        assert_eq!(first["object"]["kasm_id"], "a");
+    }
+
+    #[test]
+    fn yaml_events_are_separate_documents() {
+        let previous = vec![session("a", "running")];
+        let events = diff_events(&previous, &[]);
+        let out = render_events(&events, &OutputFormat::Yaml).unwrap();
+        assert!(out.starts_with("---\n"));
+        assert!(out.contains("type: DELETED"));
+    }
+}
@@ -1,5 +1,17 @@
 use kasmctl::api::KasmClient;
-use kasmctl::config::model::Context;
+use kasmctl::api::error::ApiError;
+use kasmctl::config::model::{Context, RetryConfig};
+
+/// Retry quickly so that tests of transient failures stay fast.
+fn fast_retry(max_retries: u32, retry_mutating: bool) -> Option<RetryConfig> {
+    Some(RetryConfig {
+        max_retries,
+        base_delay_ms: 1,
+        max_delay_ms: 1,
+        jitter: false,
+        retry_mutating,
+    })
+}
 
 fn test_context(server_url: &str) -> Context {
     Context {
@@ -8,6 +20,10 @@ fn test_context(server_url: &str) -> Context {
         api_secret: "test-secret".into(),
         insecure_skip_tls_verify: false,
         timeout_seconds: None,
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
     }
 }
 
@@ -32,6 +48,28 @@ fn post_injects_api_credentials() {
     mock.assert();
 }
 
+// --- Dry run ---
+
+#[test]
+fn dry_run_resolves_targets_but_does_not_stop() {
+    let mut server = mockito::Server::new();
+    let list = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(200)
+        .with_body(r#"{"kasms":[{"kasm_id":"abc-123","user_id":"user-1"}]}"#)
+        .create();
+    let stop = server.mock("POST", "/api/stop_kasm").expect(0).create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap().with_dry_run(true);
+    let user_id = client.resolve_user_id("abc-123").unwrap();
+    let err = client.stop_kasm("abc-123", &user_id).unwrap_err();
+
+    assert!(ApiError::is_dry_run(&err));
+    list.assert();
+    stop.assert();
+}
+
 // --- get_kasms ---
 
 #[test]
@@ -251,9 +289,11 @@ fn api_http_error_status() {
         .mock("POST", "/api/public/get_kasms")
         .with_status(500)
         .with_body(r#"{"something":"else"}"#)
+        .expect(4)
         .create();
 
-    let ctx = test_context(&server.url());
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(3, false);
     let client = KasmClient::new(&ctx).unwrap();
     let result = client.get_kasms();
 
@@ -287,6 +327,158 @@ fn api_deserialization_error() {
     mock.assert();
 }
 
+#[test]
+fn api_errors_are_classified() {
+    let mut server = mockito::Server::new();
+    let _unauthorized = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(401)
+        .create();
+    let _not_found = server
+        .mock("POST", "/api/public/get_kasm_status")
+        .with_status(200)
+        .with_body(r#"{"error_message":"Kasm not found"}"#)
+        .create();
+    let _forbidden = server
+        .mock("POST", "/api/admin/get_users")
+        .with_status(403)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+
+    let err = client.get_kasms().unwrap_err();
+    assert!(
+        matches!(ApiError::find(&err), Some(ApiError::Unauthorized(_))),
+        "error was: {err:?}"
+    );
+    let err = client.get_kasm_status("k1", "u1").unwrap_err();
+    assert!(
+        matches!(ApiError::find(&err), Some(ApiError::NotFound(_))),
+        "error was: {err:?}"
+    );
+    assert!(err.to_string().contains("Kasm not found"));
+    let err = client.get_users().unwrap_err();
+    assert!(
+        matches!(ApiError::find(&err), Some(ApiError::Forbidden(_))),
+        "error was: {err:?}"
+    );
+}
+
+// --- Retry ---
+
+#[test]
+fn read_only_calls_retry_transient_failures() {
+    let mut server = mockito::Server::new();
+    let failing = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(503)
+        .expect(2)
+        .create();
+    let ok = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(200)
+        .with_body(r#"{"kasms":[]}"#)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, false);
+    let client = KasmClient::new(&ctx).unwrap();
+    // The first matching mock answers until it has been hit as often as expected.
+    let sessions = client.get_kasms().unwrap();
+
+    assert!(sessions.is_empty());
+    failing.assert();
+    ok.assert();
+}
+
+#[test]
+fn retries_stop_after_max_retries() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(503)
+        .expect(3)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, false);
+    let client = KasmClient::new(&ctx).unwrap();
+    let err = client.get_kasms().unwrap_err().to_string();
+
+    assert!(err.contains("503"), "error was: {err}");
+    mock.assert();
+}
+
+#[test]
+fn client_errors_are_not_retried() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(200)
+        .with_body(r#"{"error_message":"unauthorized"}"#)
+        .expect(1)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, false);
+    let client = KasmClient::new(&ctx).unwrap();
+
+    assert!(client.get_kasms().is_err());
+    mock.assert();
+}
+
+#[test]
+fn mutating_calls_are_not_retried_by_default() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/stop_kasm")
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, false);
+    let client = KasmClient::new(&ctx).unwrap();
+
+    assert!(client.stop_kasm("k1", "u1").is_err());
+    mock.assert();
+}
+
+#[test]
+fn mutating_calls_retry_when_opted_in() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/stop_kasm")
+        .with_status(503)
+        .expect(3)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, true);
+    let client = KasmClient::new(&ctx).unwrap();
+
+    assert!(client.stop_kasm("k1", "u1").is_err());
+    mock.assert();
+}
+
+#[test]
+fn creating_calls_are_never_retried() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/request_kasm")
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, true);
+    let client = KasmClient::new(&ctx).unwrap();
+
+    assert!(client.request_kasm("img", None).is_err());
+    mock.assert();
+}
+
 // --- URL construction ---
 
 #[test]
@@ -1059,57 +1251,96 @@ fn get_zones_empty_list() {
     mock.assert();
 }
 
-// --- stop_kasm ---
+// --- create_zone ---
 
 #[test]
-fn stop_kasm_success() {
+fn create_zone_sends_target_zone_and_omits_none_fields() {
+    use kasmctl::api::zones::CreateZoneParams;
+
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/stop_kasm")
+        .mock("POST", "/api/admin/create_zone")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+            r#"{"target_zone":{"zone_name":"us-east","proxy_port":8443}}"#.into(),
         ))
         .with_status(200)
-        .with_body(r#"{}"#)
+        .with_body(r#"{"zone":{"zone_id":"zone-003","zone_name":"us-east","proxy_port":8443}}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client.stop_kasm("abc-123", "user-001").unwrap();
+    let params = CreateZoneParams {
+        zone_name: "us-east".into(),
+        allow_origin_domain: None,
+        upstream_auth_address: None,
+        load_balancing_strategy: None,
+        search_alternate_zones: None,
+        prioritize_static_agents: None,
+        proxy_connections: None,
+        proxy_hostname: None,
+        proxy_path: None,
+        proxy_port: Some(8443),
+    };
+    let zone = client.create_zone(&params).unwrap();
 
+    assert_eq!(zone.zone_id, "zone-003");
+    assert_eq!(zone.proxy_port, Some(8443));
     mock.assert();
+
+    let body = serde_json::to_value(&params).unwrap();
+    assert!(body.get("proxy_hostname").is_none());
 }
 
-// --- pause_kasm ---
+// --- update_zone ---
 
 #[test]
-fn pause_kasm_success() {
+fn update_zone_sends_only_changed_fields() {
+    use kasmctl::api::zones::UpdateZoneRequest;
+
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/pause_kasm")
+        .mock("POST", "/api/admin/update_zone")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+            r#"{"target_zone":{"zone_id":"zone-001","search_alternate_zones":false}}"#.into(),
         ))
         .with_status(200)
-        .with_body(r#"{}"#)
+        .with_body(r#"{"zone":{"zone_id":"zone-001","search_alternate_zones":false}}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client.pause_kasm("abc-123", "user-001").unwrap();
+    let req = UpdateZoneRequest {
+        zone_id: "zone-001".into(),
+        zone_name: None,
+        allow_origin_domain: None,
+        upstream_auth_address: None,
+        load_balancing_strategy: None,
+        search_alternate_zones: Some(false),
+        prioritize_static_agents: None,
+        proxy_connections: None,
+        proxy_hostname: None,
+        proxy_path: None,
+        proxy_port: None,
+    };
+    let zone = client.update_zone(&req).unwrap();
 
+    assert_eq!(zone.search_alternate_zones, Some(false));
+    assert_eq!(
+        serde_json::to_value(&req).unwrap(),
+        serde_json::json!({"zone_id": "zone-001", "search_alternate_zones": false})
+    );
     mock.assert();
 }
 
-// --- resume_kasm ---
+// --- delete_zone ---
 
 #[test]
-fn resume_kasm_success() {
+fn delete_zone_success() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/resume_kasm")
+        .mock("POST", "/api/admin/delete_zone")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+            r#"{"target_zone":{"zone_id":"zone-002"}}"#.into(),
         ))
         .with_status(200)
         .with_body(r#"{}"#)
@@ -1117,364 +1348,1336 @@ fn resume_kasm_success() {
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client.resume_kasm("abc-123", "user-001").unwrap();
+    client.delete_zone("zone-002").unwrap();
 
     mock.assert();
 }
 
-// ===================== get_agents =====================
+// --- resolve_zone ---
+
+const TWO_ZONES_BODY: &str = r#"{"zones":[
+    {"zone_id":"zone-001","zone_name":"us-east"},
+    {"zone_id":"zone-002","zone_name":"eu-west"}
+]}"#;
 
 #[test]
-fn get_agents_success() {
+fn resolve_zone_by_name_case_insensitive() {
     let mut server = mockito::Server::new();
-    let mock = server
-        .mock("POST", "/api/admin/get_agents")
+    let _mock = server
+        .mock("POST", "/api/public/get_zones")
         .with_status(200)
-        .with_body(
-            r#"{"agents":[
-                {"agent_id":"agent-001","hostname":"kasm-agent-1","status":"running","enabled":true},
-                {"agent_id":"agent-002","hostname":"kasm-agent-2","status":"stopped","enabled":false}
-            ]}"#,
-        )
+        .with_body(TWO_ZONES_BODY)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let agents = client.get_agents().unwrap();
-
-    assert_eq!(agents.len(), 2);
-    assert_eq!(agents[0].agent_id, "agent-001");
-    assert_eq!(agents[0].hostname.as_deref(), Some("kasm-agent-1"));
-    assert_eq!(agents[0].enabled, Some(true));
-    assert_eq!(agents[1].agent_id, "agent-002");
-    assert_eq!(agents[1].enabled, Some(false));
 
-    mock.assert();
+    assert_eq!(client.resolve_zone("EU-West").unwrap().zone_id, "zone-002");
+    assert_eq!(
+        client
+            .resolve_zone("zone-001")
+            .unwrap()
+            .zone_name
+            .as_deref(),
+        Some("us-east")
+    );
 }
 
 #[test]
-fn get_agents_empty_list() {
+fn resolve_zone_reports_ambiguous_and_missing() {
     let mut server = mockito::Server::new();
-    let mock = server
-        .mock("POST", "/api/admin/get_agents")
+    let _mock = server
+        .mock("POST", "/api/public/get_zones")
         .with_status(200)
-        .with_body(r#"{"agents":[]}"#)
+        .with_body(TWO_ZONES_BODY)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let agents = client.get_agents().unwrap();
 
-    assert!(agents.is_empty());
-
-    mock.assert();
+    let err = client.resolve_zone("zone-").unwrap_err();
+    assert!(matches!(ApiError::find(&err), Some(ApiError::Ambiguous(_))));
+    let err = client.resolve_zone("ap-south").unwrap_err();
+    assert!(matches!(ApiError::find(&err), Some(ApiError::NotFound(_))));
 }
 
-// ===================== update_agent =====================
+// --- stop_kasm ---
 
 #[test]
-fn update_agent_success() {
-    use kasmctl::api::agents::UpdateAgentRequest;
-
+fn stop_kasm_success() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/admin/update_agent")
+        .mock("POST", "/api/stop_kasm")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+        ))
         .with_status(200)
-        .with_body(
-            r#"{"agent":{"agent_id":"agent-001","hostname":"kasm-agent-1","enabled":false}}"#,
-        )
+        .with_body(r#"{}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let req = UpdateAgentRequest {
-        agent_id: "agent-001".into(),
-        enabled: Some(false),
-        cores_override: None,
-        memory_override: None,
-        gpus_override: None,
-        auto_prune_images: None,
-    };
-    let agent = client.update_agent(&req).unwrap();
-
-    assert_eq!(agent.agent_id, "agent-001");
-    assert_eq!(agent.enabled, Some(false));
+    client.stop_kasm("abc-123", "user-001").unwrap();
 
     mock.assert();
 }
 
-#[test]
-fn update_agent_sends_target_agent_wrapper() {
-    use kasmctl::api::agents::UpdateAgentRequest;
+// --- pause_kasm ---
 
+#[test]
+fn pause_kasm_success() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/admin/update_agent")
+        .mock("POST", "/api/pause_kasm")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"target_agent":{"agent_id":"agent-001"}}"#.into(),
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
         ))
         .with_status(200)
-        .with_body(r#"{"agent":{"agent_id":"agent-001"}}"#)
+        .with_body(r#"{}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let req = UpdateAgentRequest {
-        agent_id: "agent-001".into(),
-        enabled: None,
-        cores_override: None,
-        memory_override: None,
-        gpus_override: None,
-        auto_prune_images: None,
-    };
-    client.update_agent(&req).unwrap();
+    client.pause_kasm("abc-123", "user-001").unwrap();
 
     mock.assert();
 }
 
-#[test]
-fn update_agent_omits_none_fields() {
-    use kasmctl::api::agents::UpdateAgentRequest;
+// --- resume_kasm ---
 
+#[test]
+fn resume_kasm_success() {
     let mut server = mockito::Server::new();
-    // Only agent_id should be in target_agent — no enabled, cores_override, etc.
     let mock = server
-        .mock("POST", "/api/admin/update_agent")
+        .mock("POST", "/api/resume_kasm")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"target_agent":{"agent_id":"agent-001"}}"#.into(),
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
         ))
         .with_status(200)
-        .with_body(r#"{"agent":{"agent_id":"agent-001"}}"#)
+        .with_body(r#"{}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let req = UpdateAgentRequest {
-        agent_id: "agent-001".into(),
-        enabled: None,
-        cores_override: None,
-        memory_override: None,
-        gpus_override: None,
-        auto_prune_images: None,
-    };
-    client.update_agent(&req).unwrap();
+    client.resume_kasm("abc-123", "user-001").unwrap();
 
     mock.assert();
 }
 
-// --- URL construction ---
+// --- keepalive ---
 
 #[test]
-fn url_construction_without_trailing_slash() {
+fn keepalive_success() {
     let mut server = mockito::Server::new();
-
     let mock = server
-        .mock("POST", "/api/public/get_kasms")
+        .mock("POST", "/api/public/keepalive")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+        ))
         .with_status(200)
-        .with_body(r#"{"kasms":[]}"#)
+        .with_body(r#"{"usage_reached":false}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client.get_kasms().unwrap();
+    client.keepalive("abc-123", "user-001").unwrap();
 
     mock.assert();
 }
 
-// ===================== get_report =====================
-
 #[test]
-fn get_report_current_kasms_success() {
+fn keepalive_fails_when_usage_limit_reached() {
     let mut server = mockito::Server::new();
-    let mock = server
-        .mock("POST", "/api/admin/get_report")
+    let _mock = server
+        .mock("POST", "/api/public/keepalive")
         .with_status(200)
-        .with_body(r#"{"data": 23}"#)
+        .with_body(r#"{"usage_reached":true}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let err = client
+        .keepalive("abc-123", "user-001")
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("usage limit reached"), "error was: {err}");
+}
+
+#[test]
+fn keepalive_is_not_retried_by_default_but_is_with_retry_mutating() {
+    let mut server = mockito::Server::new();
+    let _fail = server
+        .mock("POST", "/api/public/keepalive")
+        .with_status(503)
+        .expect(1)
+        .create();
+    let _ok = server
+        .mock("POST", "/api/public/keepalive")
+        .with_status(200)
+        .with_body(r#"{}"#)
+        .create();
+
+    let mut ctx = test_context(&server.url());
+    ctx.retry = fast_retry(2, false);
+    let client = KasmClient::new(&ctx).unwrap();
+    assert!(client.keepalive("abc-123", "user-001").is_err());
+
+    ctx.retry = fast_retry(2, true);
+    let client = KasmClient::new(&ctx).unwrap();
+    client.keepalive("abc-123", "user-001").unwrap();
+}
+
+// --- session sharing ---
+
+#[test]
+fn create_kasm_share_id_returns_share_id() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/create_kasm_share_id")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"share_id":"sh-42"}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let share_id = client.create_kasm_share_id("abc-123", "user-001").unwrap();
+
+    assert_eq!(share_id, "sh-42");
+    assert_eq!(
+        client.share_url(&share_id),
+        format!("{}/#/join/sh-42", server.url())
+    );
+    mock.assert();
+}
+
+#[test]
+fn delete_kasm_share_id_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/delete_kasm_share_id")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    client.delete_kasm_share_id("abc-123", "user-001").unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn get_kasm_viewers_defaults_to_empty() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_kasm_viewers")
+        .with_status(200)
+        .with_body(r#"{"viewers":[{"user_id":"user-002","username":"student1@example.com"}]}"#)
+        .create();
+    let _empty = server
+        .mock("POST", "/api/public/get_kasm_viewers")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"def-456"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let viewers = client.get_kasm_viewers("abc-123", "user-001").unwrap();
+    assert_eq!(viewers.len(), 1);
+    assert_eq!(viewers[0].username.as_deref(), Some("student1@example.com"));
+
+    assert!(
+        client
+            .get_kasm_viewers("def-456", "user-001")
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn join_kasm_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/join_kasm")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"share_id":"sh-42"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{"kasm_id":"abc-123","share_id":"sh-42","kasm_url":"/#/connect/join/sh-42","username":"teacher@example.com"}"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let resp = client.join_kasm("sh-42").unwrap();
+
+    assert_eq!(resp.kasm_id, "abc-123");
+    assert_eq!(resp.username.as_deref(), Some("teacher@example.com"));
+    mock.assert();
+}
+
+#[test]
+fn dry_run_does_not_share() {
+    let mut server = mockito::Server::new();
+    let share = server
+        .mock("POST", "/api/public/create_kasm_share_id")
+        .expect(0)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap().with_dry_run(true);
+    let err = client
+        .create_kasm_share_id("abc-123", "user-001")
+        .unwrap_err();
+
+    assert!(ApiError::is_dry_run(&err));
+    share.assert();
+}
+
+// --- get_kasm_screenshot ---
+
+#[test]
+fn get_kasm_screenshot_returns_image_bytes() {
+    let png: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR";
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasm_screenshot")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001","width":640}"#.into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(png)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let screenshot = client
+        .get_kasm_screenshot("abc-123", "user-001", Some(640))
+        .unwrap();
+
+    assert_eq!(screenshot.data, png);
+    assert_eq!(screenshot.extension(), "png");
+    mock.assert();
+}
+
+#[test]
+fn get_kasm_screenshot_reports_api_errors() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_kasm_screenshot")
+        .with_status(200)
+        .with_body(r#"{"error_message":"Kasm not found"}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let err = client
+        .get_kasm_screenshot("abc-123", "user-001", None)
+        .unwrap_err();
+
+    assert!(matches!(ApiError::find(&err), Some(ApiError::NotFound(_))));
+}
+
+#[test]
+fn dry_run_still_captures_screenshots() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasm_screenshot")
+        .with_status(200)
+        .with_body(vec![0xff, 0xd8, 0xff, 0xe0])
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap().with_dry_run(true);
+    let screenshot = client
+        .get_kasm_screenshot("abc-123", "user-001", None)
+        .unwrap();
+
+    assert_eq!(screenshot.extension(), "jpg");
+    mock.assert();
+}
+
+// --- session stats ---
+
+#[test]
+fn get_kasm_bottleneck_stats_unwraps_response() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasm_bottleneck_stats")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"bottleneck_stats":{"cpu":[12.5,40],"network":[0,3]}}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let stats = client
+        .get_kasm_bottleneck_stats("abc-123", "user-001")
+        .unwrap();
+
+    assert_eq!(stats.cpu, vec![12.5, 40.0]);
+    assert_eq!(stats.network, vec![0.0, 3.0]);
+    mock.assert();
+}
+
+#[test]
+fn get_kasm_frame_stats_requests_all_clients() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasm_frame_stats")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-001","client":"all"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{
+                "frame":{"resx":1280,"resy":720,"changed":10,"server_time":12},
+                "server_side":[{"process_name":"Encoding_total","time":9}],
+                "client_side":[{"client":"10.0.0.8","client_time":4,"ping":30,"processes":[{"process_name":"Decode","time":3}]}]
+            }"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let stats = client.get_kasm_frame_stats("abc-123", "user-001").unwrap();
+
+    assert_eq!(stats.frame.as_ref().unwrap().resx, Some(1280));
+    assert_eq!(stats.encode_time(), Some(9.0));
+    assert_eq!(stats.client_side[0].ping, Some(30.0));
+    assert_eq!(stats.client_side[0].processes[0].process_name, "Decode");
+    mock.assert();
+}
+
+// ===================== get_agents =====================
+
+#[test]
+fn get_agents_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_agents")
+        .with_status(200)
+        .with_body(
+            r#"{"agents":[
+                {"agent_id":"agent-001","hostname":"kasm-agent-1","status":"running","enabled":true},
+                {"agent_id":"agent-002","hostname":"kasm-agent-2","status":"stopped","enabled":false}
+            ]}"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let agents = client.get_agents().unwrap();
+
+    assert_eq!(agents.len(), 2);
+    assert_eq!(agents[0].agent_id, "agent-001");
+    assert_eq!(agents[0].hostname.as_deref(), Some("kasm-agent-1"));
+    assert_eq!(agents[0].enabled, Some(true));
+    assert_eq!(agents[1].agent_id, "agent-002");
+    assert_eq!(agents[1].enabled, Some(false));
+
+    mock.assert();
+}
+
+#[test]
+fn get_agents_empty_list() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_agents")
+        .with_status(200)
+        .with_body(r#"{"agents":[]}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let agents = client.get_agents().unwrap();
+
+    assert!(agents.is_empty());
+
+    mock.assert();
+}
+
+// ===================== update_agent =====================
+
+#[test]
+fn update_agent_success() {
+    use kasmctl::api::agents::UpdateAgentRequest;
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/update_agent")
+        .with_status(200)
+        .with_body(
+            r#"{"agent":{"agent_id":"agent-001","hostname":"kasm-agent-1","enabled":false}}"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let req = UpdateAgentRequest {
+        agent_id: "agent-001".into(),
+        enabled: Some(false),
+        cores_override: None,
+        memory_override: None,
+        gpus_override: None,
+        auto_prune_images: None,
+    };
+    let agent = client.update_agent(&req).unwrap();
+
+    assert_eq!(agent.agent_id, "agent-001");
+    assert_eq!(agent.enabled, Some(false));
+
+    mock.assert();
+}
+
+#[test]
+fn update_agent_sends_target_agent_wrapper() {
+    use kasmctl::api::agents::UpdateAgentRequest;
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/update_agent")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_agent":{"agent_id":"agent-001"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"agent":{"agent_id":"agent-001"}}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let req = UpdateAgentRequest {
+        agent_id: "agent-001".into(),
+        enabled: None,
+        cores_override: None,
+        memory_override: None,
+        gpus_override: None,
+        auto_prune_images: None,
+    };
+    client.update_agent(&req).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn update_agent_omits_none_fields() {
+    use kasmctl::api::agents::UpdateAgentRequest;
+
+    let mut server = mockito::Server::new();
+    // Only agent_id should be in target_agent — no enabled, cores_override, etc.
+    let mock = server
+        .mock("POST", "/api/admin/update_agent")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_agent":{"agent_id":"agent-001"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"agent":{"agent_id":"agent-001"}}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let req = UpdateAgentRequest {
+        agent_id: "agent-001".into(),
+        enabled: None,
+        cores_override: None,
+        memory_override: None,
+        gpus_override: None,
+        auto_prune_images: None,
+    };
+    client.update_agent(&req).unwrap();
+
+    mock.assert();
+}
+
+// --- URL construction ---
+
+#[test]
+fn url_construction_without_trailing_slash() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(200)
+        .with_body(r#"{"kasms":[]}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    client.get_kasms().unwrap();
+
+    mock.assert();
+}
+
+// ===================== get_report =====================
+
+#[test]
+fn get_report_current_kasms_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_report")
+        .with_status(200)
+        .with_body(r#"{"data": 23}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
     let report = client.get_report("current_kasms", None, None).unwrap();
 
-    assert_eq!(report.as_u64(), Some(23));
+    assert_eq!(report.as_u64(), Some(23));
+    mock.assert();
+}
+
+#[test]
+fn get_report_sends_name_field() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_report")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"name":"current_kasms"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"data": 5}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    client.get_report("current_kasms", None, None).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn get_report_injects_auth_credentials() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_report")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"api_key":"test-key","api_key_secret":"test-secret"}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"data": 0}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    client.get_report("current_kasms", None, None).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn get_report_api_error() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/admin/get_report")
+        .with_status(200)
+        .with_body(r#"{"error_message":"unauthorized"}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let result = client.get_report("current_kasms", None, None);
+
+    assert!(result.is_err());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("unauthorized"), "error was: {err}");
+}
+
+// ===================== get_agent_report =====================
+
+#[test]
+fn get_agent_report_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_agent_report")
+        .with_status(200)
+        .with_body(
+            r#"{"agents":[
+                {"name":"web-prod-1","health":"Healthy","kasms":12,"memory_total":34359738368,"memory_used":5583457280},
+                {"name":"web-prod-2","health":"Unhealthy"}
+            ]}"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let agents = client.get_agent_report().unwrap();
+
+    assert_eq!(agents.len(), 2);
+    assert_eq!(agents[0].name, "web-prod-1");
+    assert_eq!(agents[0].health.as_deref(), Some("Healthy"));
+    assert_eq!(agents[0].kasms, Some(12));
+    assert_eq!(agents[0].memory_total, Some(34359738368));
+    assert_eq!(agents[0].memory_used, Some(5583457280));
+    assert_eq!(agents[1].name, "web-prod-2");
+    assert_eq!(agents[1].health.as_deref(), Some("Unhealthy"));
+    assert_eq!(agents[1].kasms, None);
+
+    mock.assert();
+}
+
+#[test]
+fn get_agent_report_empty_list() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_agent_report")
+        .with_status(200)
+        .with_body(r#"{"agents":[]}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let agents = client.get_agent_report().unwrap();
+
+    assert!(agents.is_empty());
+    mock.assert();
+}
+
+#[test]
+fn get_agent_report_api_error() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/admin/get_agent_report")
+        .with_status(200)
+        .with_body(r#"{"error_message":"permission denied"}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let result = client.get_agent_report();
+
+    assert!(result.is_err());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("permission denied"), "error was: {err}");
+}
+
+// --- resolve_user_id ---
+
+#[test]
+fn resolve_user_id_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(200)
+        .with_body(
+            r#"{"kasms":[
+                {"kasm_id":"abc-123","user_id":"user-1","operational_status":"running"},
+                {"kasm_id":"def-456","user_id":"user-2","operational_status":"stopped"}
+            ]}"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let user_id = client.resolve_user_id("abc-123").unwrap();
+
+    assert_eq!(user_id, "user-1");
+    mock.assert();
+}
+
+#[test]
+fn resolve_user_id_not_found() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_kasms")
+        .with_status(200)
+        .with_body(r#"{"kasms":[]}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let result = client.resolve_user_id("abc-123");
+
+    assert!(result.is_err());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("not found"), "error was: {err}");
+}
+
+// --- exec_command_kasm ---
+
+#[test]
+fn exec_command_kasm_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/exec_command_kasm")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-1","exec_config":{"cmd":"ls -la"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    client
+        .exec_command_kasm("abc-123", "user-1", "ls -la", None, false, None)
+        .unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn exec_command_kasm_with_workdir() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/public/exec_command_kasm")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"kasm_id":"abc-123","user_id":"user-1","exec_config":{"cmd":"ls","workdir":"/tmp"}}"#
+                .into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    client
+        .exec_command_kasm("abc-123", "user-1", "ls", Some("/tmp"), false, None)
+        .unwrap();
+
+    mock.assert();
+}
+
+// ===================== resolve_image =====================
+
+const TWO_IMAGES_BODY: &str = r#"{"images":[
+    {"image_id":"aaaa1111-bbbb-cccc-dddd-eeee00000001","friendly_name":"Ubuntu Desktop","enabled":true},
+    {"image_id":"aaaa2222-bbbb-cccc-dddd-eeee00000002","friendly_name":"Kali Linux","enabled":true}
+]}"#;
+
+#[test]
+fn resolve_image_exact_id_match() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_images")
+        .with_status(200)
+        .with_body(TWO_IMAGES_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let image = client
+        .resolve_image("aaaa1111-bbbb-cccc-dddd-eeee00000001")
+        .unwrap();
+
+    assert_eq!(image.image_id, "aaaa1111-bbbb-cccc-dddd-eeee00000001");
+    assert_eq!(image.friendly_name.as_deref(), Some("Ubuntu Desktop"));
+}
+
+#[test]
+fn resolve_image_prefix_match() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_images")
+        .with_status(200)
+        .with_body(TWO_IMAGES_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    // "aaaa1111" is a prefix that matches only the first image
+    let image = client.resolve_image("aaaa1111").unwrap();
+
+    assert_eq!(image.image_id, "aaaa1111-bbbb-cccc-dddd-eeee00000001");
+}
+
+#[test]
+fn resolve_image_friendly_name_match() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_images")
+        .with_status(200)
+        .with_body(TWO_IMAGES_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let image = client.resolve_image("Kali Linux").unwrap();
+
+    assert_eq!(image.image_id, "aaaa2222-bbbb-cccc-dddd-eeee00000002");
+    assert_eq!(image.friendly_name.as_deref(), Some("Kali Linux"));
+}
+
+#[test]
+fn resolve_image_friendly_name_case_insensitive() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_images")
+        .with_status(200)
+        .with_body(TWO_IMAGES_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let image = client.resolve_image("kali linux").unwrap();
+
+    assert_eq!(image.image_id, "aaaa2222-bbbb-cccc-dddd-eeee00000002");
+}
+
+#[test]
+fn resolve_image_ambiguous_prefix_error() {
+    let mut server = mockito::Server::new();
+    // Both images share the "aaaa" prefix
+    let _mock = server
+        .mock("POST", "/api/public/get_images")
+        .with_status(200)
+        .with_body(TWO_IMAGES_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let result = client.resolve_image("aaaa");
+
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(matches!(ApiError::find(&err), Some(ApiError::Ambiguous(_))));
+    let err = err.to_string();
+    assert!(
+        err.contains("ambiguous"),
+        "expected 'ambiguous' in error, got: {err}"
+    );
+}
+
+#[test]
+fn resolve_image_not_found_error() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/public/get_images")
+        .with_status(200)
+        .with_body(TWO_IMAGES_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let result = client.resolve_image("nonexistent-image");
+
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(matches!(ApiError::find(&err), Some(ApiError::NotFound(_))));
+    let err = err.to_string();
+    assert!(
+        err.contains("not found"),
+        "expected 'not found' in error, got: {err}"
+    );
+}
+
+// --- get_users ---
+
+#[test]
+fn get_users_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_users")
+        .with_status(200)
+        .with_body(
+            r#"{"users":[
+                {"user_id":"user-001","username":"alice@example.com","locked":false,"groups":[{"group_id":"grp-1","name":"All Users"}]},
+                {"user_id":"user-002","username":"bob@example.com","locked":true}
+            ]}"#,
+        )
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let users = client.get_users().unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].user_id, "user-001");
+    assert_eq!(users[0].username.as_deref(), Some("alice@example.com"));
+    let groups = users[0].groups.as_ref().expect("groups should be present");
+    assert_eq!(groups[0].name.as_deref(), Some("All Users"));
+    assert_eq!(users[1].locked, Some(true));
+    mock.assert();
+}
+
+#[test]
+fn get_users_empty_list() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_users")
+        .with_status(200)
+        .with_body(r#"{"users":[]}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let users = client.get_users().unwrap();
+
+    assert!(users.is_empty());
+    mock.assert();
+}
+
+// --- get_user ---
+
+#[test]
+fn get_user_sends_target_user_wrapper() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_user")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_user":{"user_id":"user-001"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"user":{"user_id":"user-001","username":"alice@example.com"}}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let user = client.get_user("user-001").unwrap();
+
+    assert_eq!(user.user_id, "user-001");
+    assert_eq!(user.username.as_deref(), Some("alice@example.com"));
+    mock.assert();
+}
+
+// --- create_user ---
+
+#[test]
+fn create_user_sends_target_user_wrapper() {
+    use kasmctl::api::users::CreateUserParams;
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/create_user")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_user":{"username":"carol@example.com","password":"hunter2","first_name":"Carol","locked":false,"disabled":false}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"user":{"user_id":"user-003","username":"carol@example.com","first_name":"Carol"}}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let params = CreateUserParams {
+        username: "carol@example.com".into(),
+        password: "hunter2".into(),
+        first_name: Some("Carol".into()),
+        last_name: None,
+        organization: None,
+        phone: None,
+        locked: false,
+        disabled: false,
+    };
+    let user = client.create_user(&params).unwrap();
+
+    assert_eq!(user.user_id, "user-003");
     mock.assert();
 }
 
 #[test]
-fn get_report_sends_name_field() {
+fn create_user_api_error() {
+    use kasmctl::api::users::CreateUserParams;
+
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/admin/get_report")
+        .mock("POST", "/api/admin/create_user")
+        .with_status(200)
+        .with_body(r#"{"error_message":"Username already exists"}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let params = CreateUserParams {
+        username: "carol@example.com".into(),
+        password: "hunter2".into(),
+        first_name: None,
+        last_name: None,
+        organization: None,
+        phone: None,
+        locked: false,
+        disabled: false,
+    };
+    let result = client.create_user(&params);
+
+    assert!(result.is_err());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("Username already exists"), "error was: {err}");
+    mock.assert();
+}
+
+// --- update_user ---
+
+#[test]
+fn update_user_omits_none_fields() {
+    use kasmctl::api::users::UpdateUserRequest;
+
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/update_user")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "api_key": "test-key",
+            "api_key_secret": "test-secret",
+            "target_user": {"user_id": "user-001", "locked": true}
+        })))
+        .with_status(200)
+        .with_body(r#"{"user":{"user_id":"user-001","locked":true}}"#)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let req = UpdateUserRequest {
+        user_id: "user-001".into(),
+        username: None,
+        password: None,
+        first_name: None,
+        last_name: None,
+        organization: None,
+        phone: None,
+        locked: Some(true),
+        disabled: None,
+    };
+    let user = client.update_user(&req).unwrap();
+
+    assert_eq!(user.locked, Some(true));
+    mock.assert();
+}
+
+// --- delete_user ---
+
+#[test]
+fn delete_user_sends_force_flag() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/delete_user")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"name":"current_kasms"}"#.into(),
+            r#"{"target_user":{"user_id":"user-001"},"force":true}"#.into(),
         ))
         .with_status(200)
-        .with_body(r#"{"data": 5}"#)
+        .with_body(r#"{}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client.get_report("current_kasms", None, None).unwrap();
+    client.delete_user("user-001", true).unwrap();
 
     mock.assert();
 }
 
 #[test]
-fn get_report_injects_auth_credentials() {
+fn delete_user_without_force() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/admin/get_report")
+        .mock("POST", "/api/admin/delete_user")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"api_key":"test-key","api_key_secret":"test-secret"}"#.into(),
+            r#"{"target_user":{"user_id":"user-001"},"force":false}"#.into(),
         ))
         .with_status(200)
-        .with_body(r#"{"data": 0}"#)
+        .with_body(r#"{}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client.get_report("current_kasms", None, None).unwrap();
+    client.delete_user("user-001", false).unwrap();
 
     mock.assert();
 }
 
+// --- resolve_user ---
+
+const TWO_USERS_BODY: &str = r#"{"users":[
+    {"user_id":"aaaa1111-bbbb-cccc-dddd-eeee00000001","username":"alice@example.com"},
+    {"user_id":"aaaa2222-bbbb-cccc-dddd-eeee00000002","username":"bob@example.com"}
+]}"#;
+
 #[test]
-fn get_report_api_error() {
+fn resolve_user_by_username_case_insensitive() {
     let mut server = mockito::Server::new();
     let _mock = server
-        .mock("POST", "/api/admin/get_report")
+        .mock("POST", "/api/admin/get_users")
         .with_status(200)
-        .with_body(r#"{"error_message":"unauthorized"}"#)
+        .with_body(TWO_USERS_BODY)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let result = client.get_report("current_kasms", None, None);
+    let user = client.resolve_user("Bob@Example.com").unwrap();
 
-    assert!(result.is_err());
-    let err = result.unwrap_err().to_string();
-    assert!(err.contains("unauthorized"), "error was: {err}");
+    assert_eq!(user.user_id, "aaaa2222-bbbb-cccc-dddd-eeee00000002");
 }
 
-// ===================== get_agent_report =====================
+#[test]
+fn resolve_user_prefix_match() {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("POST", "/api/admin/get_users")
+        .with_status(200)
+        .with_body(TWO_USERS_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let user = client.resolve_user("aaaa1111").unwrap();
+
+    assert_eq!(user.username.as_deref(), Some("alice@example.com"));
+}
 
 #[test]
-fn get_agent_report_success() {
+fn resolve_user_ambiguous_prefix_error() {
     let mut server = mockito::Server::new();
-    let mock = server
-        .mock("POST", "/api/admin/get_agent_report")
+    let _mock = server
+        .mock("POST", "/api/admin/get_users")
         .with_status(200)
-        .with_body(
-            r#"{"agents":[
-                {"name":"web-prod-1","health":"Healthy","kasms":12,"memory_total":34359738368,"memory_used":5583457280},
-                {"name":"web-prod-2","health":"Unhealthy"}
-            ]}"#,
-        )
+        .with_body(TWO_USERS_BODY)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let agents = client.get_agent_report().unwrap();
+    let err = client.resolve_user("aaaa").unwrap_err().to_string();
 
-    assert_eq!(agents.len(), 2);
-    assert_eq!(agents[0].name, "web-prod-1");
-    assert_eq!(agents[0].health.as_deref(), Some("Healthy"));
-    assert_eq!(agents[0].kasms, Some(12));
-    assert_eq!(agents[0].memory_total, Some(34359738368));
-    assert_eq!(agents[0].memory_used, Some(5583457280));
-    assert_eq!(agents[1].name, "web-prod-2");
-    assert_eq!(agents[1].health.as_deref(), Some("Unhealthy"));
-    assert_eq!(agents[1].kasms, None);
+    assert!(
+        err.contains("ambiguous user prefix"),
+        "expected 'ambiguous user prefix' in error, got: {err}"
+    );
+}
+
+// ===================== groups =====================
+
+const TWO_GROUPS_BODY: &str = r#"{"groups":[
+    {"group_id":"gggg1111-bbbb-cccc-dddd-eeee00000001","name":"All Users","priority":1000,"is_system":true},
+    {"group_id":"gggg2222-bbbb-cccc-dddd-eeee00000002","name":"Engineering","description":"Dev team","priority":50,"is_system":false}
+]}"#;
+
+#[test]
+fn get_groups_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/api/admin/get_groups")
+        .with_status(200)
+        .with_body(TWO_GROUPS_BODY)
+        .create();
+
+    let ctx = test_context(&server.url());
+    let client = KasmClient::new(&ctx).unwrap();
+    let groups = client.get_groups().unwrap();
 
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name.as_deref(), Some("All Users"));
+    assert_eq!(groups[0].is_system, Some(true));
+    assert_eq!(groups[1].priority, Some(50));
+    assert_eq!(groups[1].description.as_deref(), Some("Dev team"));
     mock.assert();
 }
 
 #[test]
-fn get_agent_report_empty_list() {
+fn create_group_sends_target_group_wrapper() {
+    use kasmctl::api::groups::CreateGroupParams;
+
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/admin/get_agent_report")
+        .mock("POST", "/api/admin/create_group")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "api_key": "test-key",
+            "api_key_secret": "test-secret",
+            "target_group": {"name": "Engineering", "priority": 50}
+        })))
         .with_status(200)
-        .with_body(r#"{"agents":[]}"#)
+        .with_body(r#"{"group":{"group_id":"grp-new","name":"Engineering","priority":50}}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let agents = client.get_agent_report().unwrap();
+    let params = CreateGroupParams {
+        name: "Engineering".into(),
+        description: None,
+        priority: Some(50),
+    };
+    let group = client.create_group(&params).unwrap();
 
-    assert!(agents.is_empty());
+    assert_eq!(group.group_id, "grp-new");
     mock.assert();
 }
 
 #[test]
-fn get_agent_report_api_error() {
+fn update_group_omits_none_fields() {
+    use kasmctl::api::groups::UpdateGroupRequest;
+
     let mut server = mockito::Server::new();
-    let _mock = server
-        .mock("POST", "/api/admin/get_agent_report")
+    let mock = server
+        .mock("POST", "/api/admin/update_group")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "api_key": "test-key",
+            "api_key_secret": "test-secret",
+            "target_group": {"group_id": "grp-1", "description": "Updated"}
+        })))
         .with_status(200)
-        .with_body(r#"{"error_message":"permission denied"}"#)
+        .with_body(r#"{"group":{"group_id":"grp-1","description":"Updated"}}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let result = client.get_agent_report();
+    let req = UpdateGroupRequest {
+        group_id: "grp-1".into(),
+        name: None,
+        description: Some("Updated".into()),
+        priority: None,
+    };
+    let group = client.update_group(&req).unwrap();
 
-    assert!(result.is_err());
-    let err = result.unwrap_err().to_string();
-    assert!(err.contains("permission denied"), "error was: {err}");
+    assert_eq!(group.description.as_deref(), Some("Updated"));
+    mock.assert();
 }
 
-// --- resolve_user_id ---
-
 #[test]
-fn resolve_user_id_success() {
+fn delete_group_sends_target_group() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/public/get_kasms")
+        .mock("POST", "/api/admin/delete_group")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_group":{"group_id":"grp-1"}}"#.into(),
+        ))
         .with_status(200)
-        .with_body(
-            r#"{"kasms":[
-                {"kasm_id":"abc-123","user_id":"user-1","operational_status":"running"},
-                {"kasm_id":"def-456","user_id":"user-2","operational_status":"stopped"}
-            ]}"#,
-        )
+        .with_body(r#"{}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let user_id = client.resolve_user_id("abc-123").unwrap();
+    client.delete_group("grp-1").unwrap();
 
-    assert_eq!(user_id, "user-1");
     mock.assert();
 }
 
 #[test]
-fn resolve_user_id_not_found() {
+fn get_group_users_success() {
     let mut server = mockito::Server::new();
-    let _mock = server
-        .mock("POST", "/api/public/get_kasms")
+    let mock = server
+        .mock("POST", "/api/admin/get_users_group")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_group":{"group_id":"grp-1"}}"#.into(),
+        ))
         .with_status(200)
-        .with_body(r#"{"kasms":[]}"#)
+        .with_body(r#"{"users":[{"user_id":"user-001","username":"alice@example.com"}]}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let result = client.resolve_user_id("abc-123");
+    let users = client.get_group_users("grp-1").unwrap();
 
-    assert!(result.is_err());
-    let err = result.unwrap_err().to_string();
-    assert!(err.contains("not found"), "error was: {err}");
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].username.as_deref(), Some("alice@example.com"));
+    mock.assert();
 }
 
-// --- exec_command_kasm ---
-
 #[test]
-fn exec_command_kasm_success() {
+fn add_user_to_group_sends_both_targets() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/public/exec_command_kasm")
+        .mock("POST", "/api/public/add_user_group")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"kasm_id":"abc-123","user_id":"user-1","exec_config":{"cmd":"ls -la"}}"#.into(),
+            r#"{"target_user":{"user_id":"user-001"},"target_group":{"group_id":"grp-1"}}"#.into(),
         ))
         .with_status(200)
         .with_body(r#"{}"#)
@@ -1482,21 +2685,18 @@ fn exec_command_kasm_success() {
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client
-        .exec_command_kasm("abc-123", "user-1", "ls -la", None, false, None)
-        .unwrap();
+    client.add_user_to_group("user-001", "grp-1").unwrap();
 
     mock.assert();
 }
 
 #[test]
-fn exec_command_kasm_with_workdir() {
+fn remove_user_from_group_sends_both_targets() {
     let mut server = mockito::Server::new();
     let mock = server
-        .mock("POST", "/api/public/exec_command_kasm")
+        .mock("POST", "/api/public/remove_user_group")
         .match_body(mockito::Matcher::PartialJsonString(
-            r#"{"kasm_id":"abc-123","user_id":"user-1","exec_config":{"cmd":"ls","workdir":"/tmp"}}"#
-                .into(),
+            r#"{"target_user":{"user_id":"user-001"},"target_group":{"group_id":"grp-1"}}"#.into(),
         ))
         .with_status(200)
         .with_body(r#"{}"#)
@@ -1504,128 +2704,227 @@ fn exec_command_kasm_with_workdir() {
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    client
-        .exec_command_kasm("abc-123", "user-1", "ls", Some("/tmp"), false, None)
-        .unwrap();
+    client.remove_user_from_group("user-001", "grp-1").unwrap();
 
     mock.assert();
 }
 
-// ===================== resolve_image =====================
-
-const TWO_IMAGES_BODY: &str = r#"{"images":[
-    {"image_id":"aaaa1111-bbbb-cccc-dddd-eeee00000001","friendly_name":"Ubuntu Desktop","enabled":true},
-    {"image_id":"aaaa2222-bbbb-cccc-dddd-eeee00000002","friendly_name":"Kali Linux","enabled":true}
-]}"#;
-
 #[test]
-fn resolve_image_exact_id_match() {
+fn add_user_to_group_api_error() {
     let mut server = mockito::Server::new();
     let _mock = server
-        .mock("POST", "/api/public/get_images")
+        .mock("POST", "/api/public/add_user_group")
         .with_status(200)
-        .with_body(TWO_IMAGES_BODY)
+        .with_body(r#"{"error_message":"User is already a member of this group"}"#)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let image = client
-        .resolve_image("aaaa1111-bbbb-cccc-dddd-eeee00000001")
-        .unwrap();
+    let err = client
+        .add_user_to_group("user-001", "grp-1")
+        .unwrap_err()
+        .to_string();
 
-    assert_eq!(image.image_id, "aaaa1111-bbbb-cccc-dddd-eeee00000001");
-    assert_eq!(image.friendly_name.as_deref(), Some("Ubuntu Desktop"));
+    assert!(err.contains("already a member"), "error was: {err}");
 }
 
 #[test]
-fn resolve_image_prefix_match() {
+fn resolve_group_by_name_case_insensitive() {
     let mut server = mockito::Server::new();
     let _mock = server
-        .mock("POST", "/api/public/get_images")
+        .mock("POST", "/api/admin/get_groups")
         .with_status(200)
-        .with_body(TWO_IMAGES_BODY)
+        .with_body(TWO_GROUPS_BODY)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    // "aaaa1111" is a prefix that matches only the first image
-    let image = client.resolve_image("aaaa1111").unwrap();
+    let group = client.resolve_group("engineering").unwrap();
 
-    assert_eq!(image.image_id, "aaaa1111-bbbb-cccc-dddd-eeee00000001");
+    assert_eq!(group.group_id, "gggg2222-bbbb-cccc-dddd-eeee00000002");
 }
 
 #[test]
-fn resolve_image_friendly_name_match() {
+fn resolve_group_not_found_error() {
     let mut server = mockito::Server::new();
     let _mock = server
-        .mock("POST", "/api/public/get_images")
+        .mock("POST", "/api/admin/get_groups")
         .with_status(200)
-        .with_body(TWO_IMAGES_BODY)
+        .with_body(TWO_GROUPS_BODY)
         .create();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let image = client.resolve_image("Kali Linux").unwrap();
+    let err = client.resolve_group("Marketing").unwrap_err().to_string();
 
-    assert_eq!(image.image_id, "aaaa2222-bbbb-cccc-dddd-eeee00000002");
-    assert_eq!(image.friendly_name.as_deref(), Some("Kali Linux"));
+    assert!(
+        err.contains("group \"Marketing\" not found"),
+        "expected not-found error, got: {err}"
+    );
 }
 
+// ===================== apply =====================
+
 #[test]
-fn resolve_image_friendly_name_case_insensitive() {
+fn apply_creates_updates_and_skips_images() {
+    use kasmctl::manifest::{Applier, ApplyAction, parse_manifests};
+
     let mut server = mockito::Server::new();
-    let _mock = server
+    let list_mock = server
         .mock("POST", "/api/public/get_images")
         .with_status(200)
-        .with_body(TWO_IMAGES_BODY)
+        .with_body(
+            r#"{"images":[
+                {"image_id":"img-1","friendly_name":"Terminal","name":"kasmweb/terminal:1.18.0","cores":2.0},
+                {"image_id":"img-2","friendly_name":"Chrome","name":"kasmweb/chrome:1.17.0"}
+            ]}"#,
+        )
+        .expect(1)
+        .create();
+    let update_mock = server
+        .mock("POST", "/api/admin/update_image")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "api_key": "test-key",
+            "api_key_secret": "test-secret",
+            "target_image": {"image_id": "img-2", "name": "kasmweb/chrome:1.18.0"}
+        })))
+        .with_status(200)
+        .with_body(r#"{"image":{"image_id":"img-2","friendly_name":"Chrome","name":"kasmweb/chrome:1.18.0"}}"#)
         .create();
+    let create_mock = server
+        .mock("POST", "/api/admin/create_image")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_image":{"friendly_name":"Firefox","name":"kasmweb/firefox:1.18.0","enabled":true,"image_src":"Container"}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"image":{"image_id":"img-3","friendly_name":"Firefox"}}"#)
+        .create();
+
+    let manifests = parse_manifests(
+        r#"
+apiVersion: kasmctl/v1
+kind: Image
+spec:
+  name: kasmweb/terminal:1.18.0
+  friendly_name: Terminal
+  cores: 2
+---
+apiVersion: kasmctl/v1
+kind: Image
+spec:
+  name: kasmweb/chrome:1.18.0
+  friendly_name: Chrome
+---
+apiVersion: kasmctl/v1
+kind: Image
+spec:
+  name: kasmweb/firefox:1.18.0
+  friendly_name: Firefox
+"#,
+    )
+    .unwrap();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let image = client.resolve_image("kali linux").unwrap();
+    let mut applier = Applier::new(&client);
+    let actions: Vec<ApplyAction> = manifests
+        .iter()
+        .map(|m| applier.apply(m).unwrap().action)
+        .collect();
 
-    assert_eq!(image.image_id, "aaaa2222-bbbb-cccc-dddd-eeee00000002");
+    assert_eq!(
+        actions,
+        vec![
+            ApplyAction::Unchanged,
+            ApplyAction::Configured,
+            ApplyAction::Created
+        ]
+    );
+    list_mock.assert();
+    update_mock.assert();
+    create_mock.assert();
 }
 
 #[test]
-fn resolve_image_ambiguous_prefix_error() {
+fn apply_agent_updates_by_hostname() {
+    use kasmctl::manifest::{Applier, ApplyAction, parse_manifests};
+
     let mut server = mockito::Server::new();
-    // Both images share the "aaaa" prefix
-    let _mock = server
-        .mock("POST", "/api/public/get_images")
+    let _list = server
+        .mock("POST", "/api/admin/get_agents")
         .with_status(200)
-        .with_body(TWO_IMAGES_BODY)
+        .with_body(r#"{"agents":[{"agent_id":"agent-1","hostname":"agent-01","enabled":true}]}"#)
+        .create();
+    let update_mock = server
+        .mock("POST", "/api/admin/update_agent")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"target_agent":{"agent_id":"agent-1","enabled":false}}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"agent":{"agent_id":"agent-1","hostname":"agent-01","enabled":false}}"#)
         .create();
 
+    let manifests = parse_manifests(
+        "apiVersion: kasmctl/v1\nkind: Agent\nspec:\n  hostname: agent-01\n  enabled: false\n",
+    )
+    .unwrap();
+
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let result = client.resolve_image("aaaa");
+    let result = Applier::new(&client).apply(&manifests[0]).unwrap();
 
-    assert!(result.is_err());
-    let err = result.unwrap_err().to_string();
-    assert!(
-        err.contains("ambiguous"),
-        "expected 'ambiguous' in error, got: {err}"
-    );
+    assert_eq!(result.action, ApplyAction::Configured);
+    assert_eq!(result.to_string(), "agent/agent-01 configured");
+    update_mock.assert();
 }
 
+// ===================== diff =====================
+
 #[test]
-fn resolve_image_not_found_error() {
+fn diff_reports_drift_against_live_server() {
+    use kasmctl::diff::Differ;
+    use kasmctl::manifest::parse_manifests;
+
     let mut server = mockito::Server::new();
-    let _mock = server
-        .mock("POST", "/api/public/get_images")
+    let _list = server
+        .mock("POST", "/api/admin/get_servers")
         .with_status(200)
-        .with_body(TWO_IMAGES_BODY)
-        .create();
+        .with_body(
+            r#"{"servers":[{"server_id":"srv-1","friendly_name":"Build Box","hostname":"10.0.0.5","enabled":false,"connection_info":"secret"}]}"#,
+        )
+        .expect(1)
+        .create();
+
+    let manifests = parse_manifests(
+        r#"
+apiVersion: kasmctl/v1
+kind: Server
+spec:
+  friendly_name: Build Box
+  hostname: 10.0.0.5
+  enabled: true
+  connection_info: secret
+---
+apiVersion: kasmctl/v1
+kind: Server
+spec:
+  friendly_name: Build Box
+  hostname: 10.0.0.5
+"#,
+    )
+    .unwrap();
 
     let ctx = test_context(&server.url());
     let client = KasmClient::new(&ctx).unwrap();
-    let result = client.resolve_image("nonexistent-image");
+    let mut differ = Differ::new(&client);
 
-    assert!(result.is_err());
-    let err = result.unwrap_err().to_string();
-    assert!(
-        err.contains("not found"),
-        "expected 'not found' in error, got: {err}"
-    );
+    let drifted = differ.diff(&manifests[0]).unwrap();
+    assert!(drifted.has_drift());
+    let text = drifted.unified();
+    assert!(text.contains("-enabled: false"), "diff was: {text}");
+    assert!(text.contains("+enabled: true"), "diff was: {text}");
+    assert!(!text.contains("secret"), "diff leaked a secret: {text}");
+
+    let in_sync = differ.diff(&manifests[1]).unwrap();
+    assert!(!in_sync.has_drift());
 }
@@ -1,16 +1,26 @@
+use std::time::Duration;
+
 use clap::{CommandFactory, Parser};
 use clap_complete::Shell;
 use kasmctl::cli::config_cmd::ConfigCommand;
+use kasmctl::cli::verbs::add::AddResource;
 use kasmctl::cli::verbs::create::CreateResource;
 use kasmctl::cli::verbs::delete::DeleteResource;
 use kasmctl::cli::verbs::exec::ExecResource;
 use kasmctl::cli::verbs::get::GetResource;
+use kasmctl::cli::verbs::keepalive::KeepaliveResource;
 use kasmctl::cli::verbs::pause::PauseResource;
+use kasmctl::cli::verbs::remove::RemoveResource;
 use kasmctl::cli::verbs::resume::ResumeResource;
+use kasmctl::cli::verbs::screenshot::ScreenshotResource;
+use kasmctl::cli::verbs::share::ShareResource;
+use kasmctl::cli::verbs::stats::StatsResource;
 use kasmctl::cli::verbs::stop::StopResource;
 use kasmctl::cli::verbs::top::TopCommand;
+use kasmctl::cli::verbs::unshare::UnshareResource;
 use kasmctl::cli::verbs::update::UpdateResource;
 use kasmctl::cli::{Cli, Command};
+use kasmctl::config::model::RetryConfig;
 use kasmctl::output::OutputFormat;
 
 // --- Get commands ---
@@ -110,6 +120,78 @@ fn parse_get_session_requires_id() {
     assert!(result.is_err());
 }
 
+#[test]
+fn parse_get_sessions_watch_defaults_to_two_seconds() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions", "--watch"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    assert!(args.watch);
+    assert_eq!(args.interval, Duration::from_secs(2));
+}
+
+#[test]
+fn parse_get_sessions_watch_with_interval() {
+    let cli =
+        Cli::try_parse_from(["kasmctl", "get", "sessions", "-w", "--interval", "5s"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    assert!(args.watch);
+    assert_eq!(args.interval, Duration::from_secs(5));
+}
+
+#[test]
+fn parse_get_without_watch_is_not_watching() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "images"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    assert!(!args.watch);
+}
+
+#[test]
+fn parse_get_sort_by_and_reverse() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "get",
+        "sessions",
+        "--sort-by",
+        "keepalive_date",
+        "--reverse",
+    ])
+    .unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    assert_eq!(args.sort_by.as_deref(), Some("keepalive_date"));
+    assert!(args.reverse);
+}
+
+#[test]
+fn parse_get_without_sort_by_defaults() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "agents"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    assert!(args.sort_by.is_none());
+    assert!(!args.reverse);
+}
+
+#[test]
+fn parse_get_reverse_requires_sort_by() {
+    let result = Cli::try_parse_from(["kasmctl", "get", "sessions", "--reverse"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_get_watch_invalid_interval_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "get", "sessions", "-w", "--interval", "0s"]);
+    assert!(result.is_err());
+    let result = Cli::try_parse_from(["kasmctl", "get", "sessions", "-w", "--interval", "soon"]);
+    assert!(result.is_err());
+}
+
 // --- Get images ---
 
 #[test]
@@ -776,6 +858,54 @@ fn parse_delete_kasm_alias() {
     assert!(matches!(cli.command, Command::Delete(_)));
 }
 
+#[test]
+fn parse_delete_sessions_with_filters() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "delete",
+        "sessions",
+        "--idle-for",
+        "1d",
+        "--created-before",
+        "2025-01-01 00:00:00",
+        "--yes",
+        "--parallel",
+        "4",
+    ])
+    .unwrap();
+    let Command::Delete(args) = cli.command else {
+        panic!("expected Delete command");
+    };
+    let DeleteResource::Sessions {
+        filters,
+        yes,
+        parallel,
+    } = args.resource
+    else {
+        panic!("expected Sessions resource");
+    };
+    assert_eq!(filters.idle_for.as_deref(), Some("1d"));
+    assert_eq!(
+        filters.created_before.as_deref(),
+        Some("2025-01-01 00:00:00")
+    );
+    assert!(yes);
+    assert_eq!(parallel.get(), 4);
+}
+
+#[test]
+fn parse_delete_kasms_alias_no_filters() {
+    let cli = Cli::try_parse_from(["kasmctl", "delete", "kasms"]).unwrap();
+    let Command::Delete(args) = cli.command else {
+        panic!("expected Delete command");
+    };
+    let DeleteResource::Sessions { filters, yes, .. } = args.resource else {
+        panic!("expected Sessions resource");
+    };
+    assert!(filters.is_empty());
+    assert!(!yes);
+}
+
 // --- Delete image commands ---
 
 #[test]
@@ -874,6 +1004,175 @@ fn parse_resume_session_missing_id_fails() {
     assert!(result.is_err());
 }
 
+// --- Screenshot commands ---
+
+#[test]
+fn parse_screenshot_session() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "screenshot",
+        "session",
+        "kasm-789",
+        "-f",
+        "out.png",
+        "--width",
+        "800",
+    ])
+    .unwrap();
+    let Command::Screenshot(args) = cli.command else {
+        panic!("expected Screenshot command");
+    };
+    let ScreenshotResource::Session { id, file, width } = args.resource else {
+        panic!("expected Session resource");
+    };
+    assert_eq!(id, "kasm-789");
+    assert_eq!(file.as_deref(), Some(std::path::Path::new("out.png")));
+    assert_eq!(width, Some(800));
+}
+
+#[test]
+fn parse_screenshot_sessions_defaults_to_current_dir() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "screenshot",
+        "sessions",
+        "--status",
+        "running",
+        "--parallel",
+        "4",
+    ])
+    .unwrap();
+    let Command::Screenshot(args) = cli.command else {
+        panic!("expected Screenshot command");
+    };
+    let ScreenshotResource::Sessions {
+        filters,
+        output_dir,
+        parallel,
+        yes,
+        ..
+    } = args.resource
+    else {
+        panic!("expected Sessions resource");
+    };
+    assert_eq!(filters.status.as_deref(), Some("running"));
+    assert_eq!(output_dir, std::path::Path::new("."));
+    assert_eq!(parallel.get(), 4);
+    assert!(!yes);
+}
+
+// --- Stats commands ---
+
+#[test]
+fn parse_stats_session() {
+    let cli = Cli::try_parse_from(["kasmctl", "stats", "session", "kasm-789"]).unwrap();
+    let Command::Stats(args) = cli.command else {
+        panic!("expected Stats command");
+    };
+    let StatsResource::Session { id } = args.resource;
+    assert_eq!(id, "kasm-789");
+    assert!(!args.watch);
+}
+
+#[test]
+fn parse_stats_session_watch_with_interval() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "stats",
+        "kasm",
+        "kasm-789",
+        "-w",
+        "--interval",
+        "500ms",
+    ])
+    .unwrap();
+    let Command::Stats(args) = cli.command else {
+        panic!("expected Stats command");
+    };
+    assert!(args.watch);
+    assert_eq!(args.interval, Duration::from_millis(500));
+}
+
+// --- Keepalive commands ---
+
+#[test]
+fn parse_keepalive_session() {
+    let cli = Cli::try_parse_from(["kasmctl", "keepalive", "session", "kasm-789"]).unwrap();
+    let Command::Keepalive(args) = cli.command else {
+        panic!("expected Keepalive command");
+    };
+    let KeepaliveResource::Session { id } = args.resource else {
+        panic!("expected Session resource");
+    };
+    assert_eq!(id, "kasm-789");
+}
+
+#[test]
+fn parse_keepalive_sessions_with_filters() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "keepalive",
+        "kasms",
+        "--image",
+        "img-exam",
+        "--parallel",
+        "8",
+        "-y",
+    ])
+    .unwrap();
+    let Command::Keepalive(args) = cli.command else {
+        panic!("expected Keepalive command");
+    };
+    let KeepaliveResource::Sessions {
+        filters,
+        yes,
+        parallel,
+    } = args.resource
+    else {
+        panic!("expected Sessions resource");
+    };
+    assert_eq!(filters.image.as_deref(), Some("img-exam"));
+    assert!(yes);
+    assert_eq!(parallel.get(), 8);
+}
+
+// --- Share commands ---
+
+#[test]
+fn parse_share_session() {
+    let cli = Cli::try_parse_from(["kasmctl", "share", "session", "kasm-789"]).unwrap();
+    let Command::Share(args) = cli.command else {
+        panic!("expected Share command");
+    };
+    let ShareResource::Session { id } = args.resource;
+    assert_eq!(id, "kasm-789");
+}
+
+#[test]
+fn parse_unshare_kasm_alias() {
+    let cli = Cli::try_parse_from(["kasmctl", "unshare", "kasm", "kasm-789"]).unwrap();
+    let Command::Unshare(args) = cli.command else {
+        panic!("expected Unshare command");
+    };
+    let UnshareResource::Session { id } = args.resource;
+    assert_eq!(id, "kasm-789");
+}
+
+#[test]
+fn parse_join() {
+    let cli = Cli::try_parse_from(["kasmctl", "join", "sh-42"]).unwrap();
+    let Command::Join(args) = cli.command else {
+        panic!("expected Join command");
+    };
+    assert_eq!(args.share_id, "sh-42");
+}
+
+#[test]
+fn parse_join_missing_share_id_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "join"]);
+    assert!(result.is_err());
+}
+
 // --- Config commands ---
 
 #[test]
@@ -900,6 +1199,9 @@ fn parse_config_set_context() {
         api_key,
         api_secret,
         insecure,
+        certificate_authority,
+        client_certificate,
+        client_key,
     } = args.command
     else {
         panic!("expected SetContext");
@@ -909,6 +1211,9 @@ fn parse_config_set_context() {
     assert_eq!(api_key, "key123");
     assert_eq!(api_secret, "secret456");
     assert!(!insecure);
+    assert_eq!(certificate_authority, None);
+    assert_eq!(client_certificate, None);
+    assert_eq!(client_key, None);
 }
 
 #[test]
@@ -937,6 +1242,79 @@ fn parse_config_set_context_with_insecure() {
     assert!(insecure);
 }
 
+#[test]
+fn parse_config_set_context_with_certificates() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "config",
+        "set-context",
+        "internal",
+        "--server",
+        "https://kasm.internal",
+        "--api-key",
+        "key",
+        "--api-secret",
+        "secret",
+        "--certificate-authority",
+        "ca.pem",
+        "--client-certificate",
+        "client.pem",
+        "--client-key",
+        "client-key.pem",
+    ])
+    .unwrap();
+    let Command::Config(args) = cli.command else {
+        panic!("expected Config command");
+    };
+    let ConfigCommand::SetContext {
+        certificate_authority,
+        client_certificate,
+        client_key,
+        ..
+    } = args.command
+    else {
+        panic!("expected SetContext");
+    };
+    assert_eq!(certificate_authority.as_deref(), Some("ca.pem"));
+    assert_eq!(client_certificate.as_deref(), Some("client.pem"));
+    assert_eq!(client_key.as_deref(), Some("client-key.pem"));
+}
+
+#[test]
+fn client_certificate_requires_client_key() {
+    let result = Cli::try_parse_from([
+        "kasmctl",
+        "config",
+        "set-context",
+        "internal",
+        "--server",
+        "https://kasm.internal",
+        "--api-key",
+        "key",
+        "--api-secret",
+        "secret",
+        "--client-certificate",
+        "client.pem",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_global_certificate_authority() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "get",
+        "sessions",
+        "--certificate-authority",
+        "/etc/ssl/internal-ca.pem",
+    ])
+    .unwrap();
+    assert_eq!(
+        cli.certificate_authority.as_deref(),
+        Some("/etc/ssl/internal-ca.pem")
+    );
+}
+
 #[test]
 fn parse_config_use_context() {
     let cli = Cli::try_parse_from(["kasmctl", "config", "use-context", "prod"]).unwrap();
@@ -979,59 +1357,223 @@ fn parse_output_table_default() {
 }
 
 #[test]
-fn parse_output_invalid_fails() {
-    let result = Cli::try_parse_from(["kasmctl", "-o", "csv", "get", "sessions"]);
-    assert!(result.is_err());
+fn parse_output_wide() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions", "-o", "wide"]).unwrap();
+    assert_eq!(cli.output, OutputFormat::Wide);
 }
 
 #[test]
-fn parse_context_flag() {
-    let cli = Cli::try_parse_from(["kasmctl", "--context", "staging", "get", "sessions"]).unwrap();
-    assert_eq!(cli.context.as_deref(), Some("staging"));
+fn parse_output_csv_and_tsv() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions", "-o", "csv"]).unwrap();
+    assert_eq!(cli.output, OutputFormat::Csv { all_fields: false });
+    assert!(!cli.all_fields);
+    let cli = Cli::try_parse_from(["kasmctl", "top", "agents", "-o", "tsv"]).unwrap();
+    assert_eq!(cli.output, OutputFormat::Tsv { all_fields: false });
 }
 
 #[test]
-fn parse_server_flag() {
+fn parse_dry_run_flag() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions"]).unwrap();
+    assert!(!cli.dry_run);
     let cli = Cli::try_parse_from([
         "kasmctl",
-        "--server",
-        "https://custom.example.com",
-        "get",
+        "stop",
         "sessions",
+        "--status",
+        "running",
+        "--dry-run",
     ])
     .unwrap();
-    assert_eq!(cli.server.as_deref(), Some("https://custom.example.com"));
+    assert!(cli.dry_run);
+    let cli = Cli::try_parse_from(["kasmctl", "--dry-run", "delete", "image", "img-1"]).unwrap();
+    assert!(cli.dry_run);
 }
 
 #[test]
-fn parse_no_subcommand_fails() {
-    let result = Cli::try_parse_from(["kasmctl"]);
-    assert!(result.is_err());
+fn parse_verbose_count() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions"]).unwrap();
+    assert_eq!(cli.verbose, 0);
+    let cli = Cli::try_parse_from(["kasmctl", "-vv", "get", "sessions"]).unwrap();
+    assert_eq!(cli.verbose, 2);
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions", "-v"]).unwrap();
+    assert_eq!(cli.verbose, 1);
 }
 
-// --- Get sessions filter tests ---
-
 #[test]
-fn parse_get_sessions_with_all_filters() {
+fn parse_retry_flags() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "sessions"]).unwrap();
+    let mut retry = None;
+    cli.retry.apply(&mut retry);
+    assert_eq!(retry, None);
+
     let cli = Cli::try_parse_from([
         "kasmctl",
         "get",
         "sessions",
-        "--status",
-        "running",
-        "--image",
-        "img-1",
-        "--user",
-        "user-1",
-        "--host",
-        "host-1",
-        "--created-before",
-        "2025-01-01 00:00:00",
-        "--created-after",
-        "2024-01-01 00:00:00",
-        "--idle-since",
-        "2025-06-01 00:00:00",
-        "--idle-for",
+        "--retries",
+        "5",
+        "--retry-delay",
+        "250ms",
+        "--retry-max-delay",
+        "10s",
+        "--no-retry-jitter",
+        "--retry-mutating",
+    ])
+    .unwrap();
+    let mut retry = None;
+    cli.retry.apply(&mut retry);
+    assert_eq!(
+        retry,
+        Some(RetryConfig {
+            max_retries: 5,
+            base_delay_ms: 250,
+            max_delay_ms: 10_000,
+            jitter: false,
+            retry_mutating: true,
+        })
+    );
+}
+
+#[test]
+fn retry_flags_override_only_what_is_given() {
+    let cli = Cli::try_parse_from(["kasmctl", "--retries", "0", "health"]).unwrap();
+    let mut retry = Some(RetryConfig {
+        retry_mutating: true,
+        ..RetryConfig::default()
+    });
+    cli.retry.apply(&mut retry);
+    assert_eq!(
+        retry,
+        Some(RetryConfig {
+            max_retries: 0,
+            retry_mutating: true,
+            ..RetryConfig::default()
+        })
+    );
+}
+
+#[test]
+fn retry_delay_rejects_invalid_durations() {
+    assert!(Cli::try_parse_from(["kasmctl", "get", "sessions", "--retry-delay", "0ms"]).is_err());
+    assert!(Cli::try_parse_from(["kasmctl", "get", "sessions", "--retry-delay", "soon"]).is_err());
+}
+
+#[test]
+fn parse_all_fields_flag() {
+    let cli =
+        Cli::try_parse_from(["kasmctl", "get", "sessions", "-o", "csv", "--all-fields"]).unwrap();
+    assert!(cli.all_fields);
+    assert_eq!(
+        cli.output.with_all_fields(),
+        Ok(OutputFormat::Csv { all_fields: true })
+    );
+}
+
+#[test]
+fn all_fields_requires_csv_or_tsv() {
+    assert!(OutputFormat::Json.with_all_fields().is_err());
+    assert!(OutputFormat::Table.with_all_fields().is_err());
+}
+
+#[test]
+fn parse_output_name() {
+    let cli = Cli::try_parse_from(["kasmctl", "-o", "name", "get", "sessions"]).unwrap();
+    assert_eq!(cli.output, OutputFormat::Name);
+}
+
+#[test]
+fn parse_output_jsonpath() {
+    let cli =
+        Cli::try_parse_from(["kasmctl", "get", "sessions", "-o", "jsonpath={.kasm_id}"]).unwrap();
+    let OutputFormat::JsonPath(path) = cli.output else {
+        panic!("expected JsonPath output");
+    };
+    assert_eq!(path.to_string(), "{.kasm_id}");
+}
+
+#[test]
+fn parse_output_custom_columns() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "get",
+        "sessions",
+        "-o",
+        "custom-columns=ID:.kasm_id,HOST:.hostname",
+    ])
+    .unwrap();
+    let OutputFormat::CustomColumns(columns) = cli.output else {
+        panic!("expected CustomColumns output");
+    };
+    let headers: Vec<&str> = columns.iter().map(|c| c.header.as_str()).collect();
+    assert_eq!(headers, ["ID", "HOST"]);
+}
+
+#[test]
+fn parse_output_invalid_jsonpath_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "-o", "jsonpath={.kasm_id", "get", "sessions"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_output_invalid_custom_columns_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "-o", "custom-columns=ID", "get", "sessions"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_output_invalid_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "-o", "xml", "get", "sessions"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_context_flag() {
+    let cli = Cli::try_parse_from(["kasmctl", "--context", "staging", "get", "sessions"]).unwrap();
+    assert_eq!(cli.context.as_deref(), Some("staging"));
+}
+
+#[test]
+fn parse_server_flag() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "--server",
+        "https://custom.example.com",
+        "get",
+        "sessions",
+    ])
+    .unwrap();
+    assert_eq!(cli.server.as_deref(), Some("https://custom.example.com"));
+}
+
+#[test]
+fn parse_no_subcommand_fails() {
+    let result = Cli::try_parse_from(["kasmctl"]);
+    assert!(result.is_err());
+}
+
+// --- Get sessions filter tests ---
+
+#[test]
+fn parse_get_sessions_with_all_filters() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "get",
+        "sessions",
+        "--status",
+        "running",
+        "--image",
+        "img-1",
+        "--user",
+        "user-1",
+        "--host",
+        "host-1",
+        "--created-before",
+        "2025-01-01 00:00:00",
+        "--created-after",
+        "2024-01-01 00:00:00",
+        "--idle-since",
+        "2025-06-01 00:00:00",
+        "--idle-for",
         "2h",
     ])
     .unwrap();
@@ -1066,7 +1608,7 @@ fn parse_stop_sessions_no_filters() {
     let Command::Stop(args) = cli.command else {
         panic!("expected Stop command");
     };
-    let StopResource::Sessions { filters, yes } = args.resource else {
+    let StopResource::Sessions { filters, yes, .. } = args.resource else {
         panic!("expected Sessions resource");
     };
     assert!(filters.is_empty());
@@ -1131,6 +1673,89 @@ fn parse_stop_sessions_all_filters() {
     assert_eq!(filters.idle_for.as_deref(), Some("1h30m"));
 }
 
+#[test]
+fn parse_stop_sessions_field_selector() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "stop",
+        "sessions",
+        "--status",
+        "running",
+        "--field-selector",
+        "hostname=~agent-0[1-3],image.friendly_name!=Terminal",
+    ])
+    .unwrap();
+    let Command::Stop(args) = cli.command else {
+        panic!("expected Stop command");
+    };
+    let StopResource::Sessions { filters, .. } = args.resource else {
+        panic!("expected Sessions resource");
+    };
+    assert_eq!(filters.status.as_deref(), Some("running"));
+    assert_eq!(
+        filters.field_selector.map(|s| s.to_string()).as_deref(),
+        Some("hostname=~agent-0[1-3],image.friendly_name!=Terminal")
+    );
+}
+
+#[test]
+fn parse_field_selector_unknown_field_fails() {
+    let result =
+        Cli::try_parse_from(["kasmctl", "get", "images", "--field-selector", "memroy>2GB"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_field_selector_invalid_regex_fails() {
+    let result = Cli::try_parse_from([
+        "kasmctl",
+        "exec",
+        "sessions",
+        "--field-selector",
+        "hostname=~agent-[",
+        "--",
+        "ls",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_bulk_sessions_parallel() {
+    let cli = Cli::try_parse_from(["kasmctl", "stop", "sessions"]).unwrap();
+    let Command::Stop(args) = cli.command else {
+        panic!("expected Stop command");
+    };
+    let StopResource::Sessions { parallel, .. } = args.resource else {
+        panic!("expected Sessions resource");
+    };
+    assert_eq!(parallel.get(), 1);
+
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "exec",
+        "sessions",
+        "--parallel",
+        "16",
+        "--",
+        "ls",
+    ])
+    .unwrap();
+    let Command::Exec(args) = cli.command else {
+        panic!("expected Exec command");
+    };
+    let ExecResource::Sessions { parallel, cmd, .. } = args.resource else {
+        panic!("expected Sessions resource");
+    };
+    assert_eq!(parallel.get(), 16);
+    assert_eq!(cmd, ["ls"]);
+}
+
+#[test]
+fn parse_bulk_sessions_parallel_zero_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "pause", "sessions", "--parallel", "0"]);
+    assert!(result.is_err());
+}
+
 #[test]
 fn parse_stop_sessions_yes_flag() {
     let cli = Cli::try_parse_from(["kasmctl", "stop", "sessions", "--yes"]).unwrap();
@@ -1172,7 +1797,7 @@ fn parse_pause_sessions_no_filters() {
     let Command::Pause(args) = cli.command else {
         panic!("expected Pause command");
     };
-    let PauseResource::Sessions { filters, yes } = args.resource else {
+    let PauseResource::Sessions { filters, yes, .. } = args.resource else {
         panic!("expected Sessions resource");
     };
     assert!(filters.is_empty());
@@ -1278,7 +1903,7 @@ fn parse_resume_sessions_no_filters() {
     let Command::Resume(args) = cli.command else {
         panic!("expected Resume command");
     };
-    let ResumeResource::Sessions { filters, yes } = args.resource else {
+    let ResumeResource::Sessions { filters, yes, .. } = args.resource else {
         panic!("expected Sessions resource");
     };
     assert!(filters.is_empty());
@@ -2076,6 +2701,89 @@ fn parse_delete_server_missing_id_fails() {
     assert!(result.is_err());
 }
 
+// --- Zone commands ---
+
+#[test]
+fn parse_create_zone_with_flags() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "create",
+        "zone",
+        "--name",
+        "us-east",
+        "--search-alternate-zones",
+        "false",
+        "--proxy-port",
+        "8443",
+    ])
+    .unwrap();
+    let Command::Create(args) = cli.command else {
+        panic!("expected Create command");
+    };
+    let CreateResource::Zone {
+        name,
+        search_alternate_zones,
+        proxy_port,
+        proxy_hostname,
+        ..
+    } = args.resource
+    else {
+        panic!("expected Zone resource");
+    };
+    assert_eq!(name, "us-east");
+    assert_eq!(search_alternate_zones, Some(false));
+    assert_eq!(proxy_port, Some(8443));
+    assert!(proxy_hostname.is_none());
+}
+
+#[test]
+fn parse_create_zone_missing_name_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "create", "zone"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_update_zone_with_rename() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "update",
+        "zone",
+        "us-east",
+        "--name",
+        "us-east-1",
+        "--proxy-connections",
+        "true",
+    ])
+    .unwrap();
+    let Command::Update(args) = cli.command else {
+        panic!("expected Update command");
+    };
+    let UpdateResource::Zone {
+        id,
+        name,
+        proxy_connections,
+        ..
+    } = args.resource
+    else {
+        panic!("expected Zone resource");
+    };
+    assert_eq!(id, "us-east");
+    assert_eq!(name.as_deref(), Some("us-east-1"));
+    assert_eq!(proxy_connections, Some(true));
+}
+
+#[test]
+fn parse_delete_zone() {
+    let cli = Cli::try_parse_from(["kasmctl", "delete", "zone", "eu-west"]).unwrap();
+    let Command::Delete(args) = cli.command else {
+        panic!("expected Delete command");
+    };
+    let DeleteResource::Zone { id } = args.resource else {
+        panic!("expected Zone resource");
+    };
+    assert_eq!(id, "eu-west");
+}
+
 // --- Health commands ---
 
 #[test]
@@ -2139,6 +2847,51 @@ fn parse_top_rejects_unknown_subcommand() {
     assert!(result.is_err());
 }
 
+#[test]
+fn parse_top_interactive_defaults() {
+    let cli = Cli::try_parse_from(["kasmctl", "top", "--interactive"]).unwrap();
+    let Command::Top(args) = cli.command else {
+        panic!("expected Top command");
+    };
+    assert!(args.interactive);
+    assert!(args.command.is_none());
+    assert_eq!(args.interval, Duration::from_secs(2));
+}
+
+#[test]
+fn parse_top_interactive_with_interval() {
+    let cli = Cli::try_parse_from(["kasmctl", "top", "-i", "--interval", "10s"]).unwrap();
+    let Command::Top(args) = cli.command else {
+        panic!("expected Top command");
+    };
+    assert!(args.interactive);
+    assert_eq!(args.interval, Duration::from_secs(10));
+}
+
+#[test]
+fn parse_top_agents_sort_by() {
+    let cli =
+        Cli::try_parse_from(["kasmctl", "top", "agents", "--sort-by", "memory_free"]).unwrap();
+    let Command::Top(args) = cli.command else {
+        panic!("expected Top command");
+    };
+    assert!(matches!(args.command, Some(TopCommand::Agents)));
+    assert_eq!(args.sort_by.as_deref(), Some("memory_free"));
+    assert!(!args.reverse);
+}
+
+#[test]
+fn parse_top_interval_requires_interactive() {
+    let result = Cli::try_parse_from(["kasmctl", "top", "--interval", "10s"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_top_interactive_conflicts_with_agents() {
+    let result = Cli::try_parse_from(["kasmctl", "top", "--interactive", "agents"]);
+    assert!(result.is_err());
+}
+
 // --- Exec commands ---
 
 #[test]
@@ -2235,3 +2988,363 @@ fn parse_exec_sessions_kasms_alias() {
     };
     assert!(matches!(args.resource, ExecResource::Sessions { .. }));
 }
+
+// --- User commands ---
+
+#[test]
+fn parse_get_users() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "users"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    let GetResource::Users { filters } = args.resource else {
+        panic!("expected Users resource");
+    };
+    assert!(filters.is_empty());
+}
+
+#[test]
+fn parse_get_user_by_id() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "user", "user-001"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    let GetResource::User { id } = args.resource else {
+        panic!("expected User resource");
+    };
+    assert_eq!(id, "user-001");
+}
+
+#[test]
+fn parse_get_users_with_filters() {
+    let cli = Cli::try_parse_from([
+        "kasmctl", "get", "users", "--name", "alice", "--realm", "ldap", "--locked",
+    ])
+    .unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    let GetResource::Users { filters } = args.resource else {
+        panic!("expected Users resource");
+    };
+    assert_eq!(filters.name.as_deref(), Some("alice"));
+    assert_eq!(filters.realm.as_deref(), Some("ldap"));
+    assert!(filters.locked);
+    assert!(!filters.unlocked);
+}
+
+#[test]
+fn parse_get_users_locked_unlocked_conflict() {
+    let result = Cli::try_parse_from(["kasmctl", "get", "users", "--locked", "--unlocked"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_create_user() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "create",
+        "user",
+        "--username",
+        "alice@example.com",
+        "--password",
+        "hunter2",
+        "--first-name",
+        "Alice",
+    ])
+    .unwrap();
+    let Command::Create(args) = cli.command else {
+        panic!("expected Create command");
+    };
+    let CreateResource::User {
+        username,
+        password,
+        first_name,
+        last_name,
+        locked,
+        disabled,
+        ..
+    } = args.resource
+    else {
+        panic!("expected User resource");
+    };
+    assert_eq!(username, "alice@example.com");
+    assert_eq!(password, "hunter2");
+    assert_eq!(first_name.as_deref(), Some("Alice"));
+    assert!(last_name.is_none());
+    assert!(!locked);
+    assert!(!disabled);
+}
+
+#[test]
+fn parse_create_user_missing_username_fails() {
+    let result = Cli::try_parse_from(["kasmctl", "create", "user", "--password", "hunter2"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_update_user_lock() {
+    let cli =
+        Cli::try_parse_from(["kasmctl", "update", "user", "user-001", "--locked", "true"]).unwrap();
+    let Command::Update(args) = cli.command else {
+        panic!("expected Update command");
+    };
+    let UpdateResource::User {
+        id,
+        locked,
+        disabled,
+        password,
+        ..
+    } = args.resource
+    else {
+        panic!("expected User resource");
+    };
+    assert_eq!(id, "user-001");
+    assert_eq!(locked, Some(true));
+    assert!(disabled.is_none());
+    assert!(password.is_none());
+}
+
+#[test]
+fn parse_delete_user() {
+    let cli = Cli::try_parse_from(["kasmctl", "delete", "user", "user-001"]).unwrap();
+    let Command::Delete(args) = cli.command else {
+        panic!("expected Delete command");
+    };
+    let DeleteResource::User { id, force } = args.resource else {
+        panic!("expected User resource");
+    };
+    assert_eq!(id, "user-001");
+    assert!(!force);
+}
+
+#[test]
+fn parse_delete_user_with_force() {
+    let cli = Cli::try_parse_from(["kasmctl", "delete", "user", "user-001", "--force"]).unwrap();
+    let Command::Delete(args) = cli.command else {
+        panic!("expected Delete command");
+    };
+    let DeleteResource::User { force, .. } = args.resource else {
+        panic!("expected User resource");
+    };
+    assert!(force);
+}
+
+// --- Group commands ---
+
+#[test]
+fn parse_get_groups_with_name_filter() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "groups", "--name", "eng"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    let GetResource::Groups { filters } = args.resource else {
+        panic!("expected Groups resource");
+    };
+    assert_eq!(filters.name.as_deref(), Some("eng"));
+}
+
+#[test]
+fn parse_get_group_by_name() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "group", "Engineering"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    let GetResource::Group { id } = args.resource else {
+        panic!("expected Group resource");
+    };
+    assert_eq!(id, "Engineering");
+}
+
+#[test]
+fn parse_get_group_members() {
+    let cli = Cli::try_parse_from(["kasmctl", "get", "group-members", "Engineering"]).unwrap();
+    let Command::Get(args) = cli.command else {
+        panic!("expected Get command");
+    };
+    let GetResource::GroupMembers { group } = args.resource else {
+        panic!("expected GroupMembers resource");
+    };
+    assert_eq!(group, "Engineering");
+}
+
+#[test]
+fn parse_create_group() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "create",
+        "group",
+        "--name",
+        "Engineering",
+        "--priority",
+        "50",
+    ])
+    .unwrap();
+    let Command::Create(args) = cli.command else {
+        panic!("expected Create command");
+    };
+    let CreateResource::Group {
+        name,
+        description,
+        priority,
+    } = args.resource
+    else {
+        panic!("expected Group resource");
+    };
+    assert_eq!(name, "Engineering");
+    assert!(description.is_none());
+    assert_eq!(priority, Some(50));
+}
+
+#[test]
+fn parse_update_group() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "update",
+        "group",
+        "grp-1",
+        "--description",
+        "Dev team",
+    ])
+    .unwrap();
+    let Command::Update(args) = cli.command else {
+        panic!("expected Update command");
+    };
+    let UpdateResource::Group {
+        id,
+        name,
+        description,
+        priority,
+    } = args.resource
+    else {
+        panic!("expected Group resource");
+    };
+    assert_eq!(id, "grp-1");
+    assert!(name.is_none());
+    assert_eq!(description.as_deref(), Some("Dev team"));
+    assert!(priority.is_none());
+}
+
+#[test]
+fn parse_delete_group() {
+    let cli = Cli::try_parse_from(["kasmctl", "delete", "group", "grp-1"]).unwrap();
+    let Command::Delete(args) = cli.command else {
+        panic!("expected Delete command");
+    };
+    let DeleteResource::Group { id } = args.resource else {
+        panic!("expected Group resource");
+    };
+    assert_eq!(id, "grp-1");
+}
+
+#[test]
+fn parse_add_user_to_group() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "add",
+        "user",
+        "alice@example.com",
+        "--group",
+        "Engineering",
+    ])
+    .unwrap();
+    let Command::Add(args) = cli.command else {
+        panic!("expected Add command");
+    };
+    let AddResource::User { user, group } = args.resource;
+    assert_eq!(user, "alice@example.com");
+    assert_eq!(group, "Engineering");
+}
+
+#[test]
+fn parse_add_user_requires_group() {
+    let result = Cli::try_parse_from(["kasmctl", "add", "user", "alice@example.com"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_remove_user_from_group() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "remove",
+        "user",
+        "alice@example.com",
+        "--group",
+        "Engineering",
+    ])
+    .unwrap();
+    let Command::Remove(args) = cli.command else {
+        panic!("expected Remove command");
+    };
+    let RemoveResource::User { user, group } = args.resource;
+    assert_eq!(user, "alice@example.com");
+    assert_eq!(group, "Engineering");
+}
+
+// --- Apply command ---
+
+#[test]
+fn parse_apply_multiple_files() {
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "apply",
+        "-f",
+        "images.yaml",
+        "--filename",
+        "servers/",
+    ])
+    .unwrap();
+    let Command::Apply(args) = cli.command else {
+        panic!("expected Apply command");
+    };
+    assert_eq!(
+        args.filenames,
+        vec![
+            std::path::PathBuf::from("images.yaml"),
+            std::path::PathBuf::from("servers/")
+        ]
+    );
+}
+
+#[test]
+fn parse_apply_requires_filename() {
+    let result = Cli::try_parse_from(["kasmctl", "apply"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_diff_with_stdin() {
+    let cli = Cli::try_parse_from(["kasmctl", "diff", "-f", "-"]).unwrap();
+    let Command::Diff(args) = cli.command else {
+        panic!("expected Diff command");
+    };
+    assert_eq!(args.filenames, vec![std::path::PathBuf::from("-")]);
+}
+
+#[test]
+fn parse_export_all_with_secrets() {
+    use kasmctl::cli::verbs::export::ExportResource;
+
+    let cli = Cli::try_parse_from([
+        "kasmctl",
+        "export",
+        "all",
+        "--output-dir",
+        "snapshot",
+        "--include-secrets",
+    ])
+    .unwrap();
+    let Command::Export(args) = cli.command else {
+        panic!("expected Export command");
+    };
+    assert_eq!(args.resource, ExportResource::All);
+    assert_eq!(args.output_dir, std::path::PathBuf::from("snapshot"));
+    assert!(args.include_secrets);
+    assert!(args.resource.includes(ExportResource::Zones));
+}
+
+#[test]
+fn parse_export_requires_output_dir() {
+    let result = Cli::try_parse_from(["kasmctl", "export", "images"]);
+    assert!(result.is_err());
+}
@@ -1,9 +1,28 @@
-use kasmctl::config::model::{Context, KasmConfig, NamedContext};
+use kasmctl::config::model::{Context, KasmConfig, NamedContext, RetryConfig};
 use kasmctl::config::{
     load_config_from, resolve_from_config, resolve_server_override, save_config_to,
 };
 use proptest::prelude::*;
 
+fn arb_retry() -> impl Strategy<Value = RetryConfig> {
+    (
+        0u32..10,
+        0u64..10_000,
+        0u64..60_000,
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(max_retries, base_delay_ms, max_delay_ms, jitter, retry_mutating)| RetryConfig {
+                max_retries,
+                base_delay_ms,
+                max_delay_ms,
+                jitter,
+                retry_mutating,
+            },
+        )
+}
+
 fn arb_context() -> impl Strategy<Value = Context> {
     (
         "[a-zA-Z0-9._/-]{1,50}",
@@ -11,14 +30,34 @@ fn arb_context() -> impl Strategy<Value = Context> {
         "[a-zA-Z0-9]{1,32}",
         any::<bool>(),
         prop::option::of(1u64..3600),
+        prop::option::of(arb_retry()),
+        prop::option::of("[a-zA-Z0-9./_-]{1,40}"),
+        prop::option::of("[a-zA-Z0-9./_-]{1,40}"),
+        prop::option::of("[a-zA-Z0-9./_-]{1,40}"),
     )
         .prop_map(
-            |(server, api_key, api_secret, insecure_skip_tls_verify, timeout_seconds)| Context {
+            |(
                 server,
                 api_key,
                 api_secret,
                 insecure_skip_tls_verify,
                 timeout_seconds,
+                retry,
+                certificate_authority,
+                client_certificate,
+                client_key,
+            )| {
+                Context {
+                    server,
+                    api_key,
+                    api_secret,
+                    insecure_skip_tls_verify,
+                    timeout_seconds,
+                    retry,
+                    certificate_authority,
+                    client_certificate,
+                    client_key,
+                }
             },
         )
 }
@@ -175,6 +214,10 @@ fn make_context(name: &str, server: &str) -> NamedContext {
             api_secret: format!("{name}-secret"),
             insecure_skip_tls_verify: false,
             timeout_seconds: None,
+            retry: None,
+            certificate_authority: None,
+            client_certificate: None,
+            client_key: None,
         },
     }
 }
@@ -253,6 +296,10 @@ fn timeout_seconds_roundtrip() {
         api_secret: "secret".into(),
         insecure_skip_tls_verify: false,
         timeout_seconds: Some(60),
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
     };
     let yaml = serde_yaml::to_string(&ctx).unwrap();
     assert!(
@@ -271,6 +318,10 @@ fn timeout_seconds_omitted_when_none() {
         api_secret: "secret".into(),
         insecure_skip_tls_verify: false,
         timeout_seconds: None,
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
     };
     let yaml = serde_yaml::to_string(&ctx).unwrap();
     assert!(
@@ -278,3 +329,88 @@ fn timeout_seconds_omitted_when_none() {
         "timeout-seconds should be omitted when None, got: {yaml}"
     );
 }
+
+// --- retry serde tests ---
+
+#[test]
+fn retry_partial_block_uses_defaults() {
+    let yaml = r#"
+server: https://example.com
+api-key: key
+api-secret: secret
+retry:
+  max-retries: 5
+  retry-mutating: true
+"#;
+    let ctx: Context = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        ctx.retry,
+        Some(RetryConfig {
+            max_retries: 5,
+            retry_mutating: true,
+            ..RetryConfig::default()
+        })
+    );
+}
+
+#[test]
+fn retry_omitted_when_none() {
+    let ctx = Context {
+        server: "https://example.com".into(),
+        api_key: "key".into(),
+        api_secret: "secret".into(),
+        insecure_skip_tls_verify: false,
+        timeout_seconds: None,
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
+    };
+    let yaml = serde_yaml::to_string(&ctx).unwrap();
+    assert!(
+        !yaml.contains("retry"),
+        "retry should be omitted when None, got: {yaml}"
+    );
+}
+
+// --- TLS serde tests ---
+
+#[test]
+fn tls_fields_roundtrip_paths_and_inline_pem() {
+    let yaml = r#"
+server: https://kasm.internal
+api-key: key
+api-secret: secret
+certificate-authority: |
+  -----BEGIN CERTIFICATE-----
+  MIIBszCCAVmgAwIBAgIUQ
+  -----END CERTIFICATE-----
+client-certificate: /etc/kasmctl/client.pem
+client-key: /etc/kasmctl/client-key.pem
+"#;
+    let ctx: Context = serde_yaml::from_str(yaml).unwrap();
+    assert!(
+        ctx.certificate_authority
+            .as_deref()
+            .unwrap()
+            .starts_with("-----BEGIN CERTIFICATE-----\n")
+    );
+    assert_eq!(
+        ctx.client_certificate.as_deref(),
+        Some("/etc/kasmctl/client.pem")
+    );
+
+    let roundtrip: Context = serde_yaml::from_str(&serde_yaml::to_string(&ctx).unwrap()).unwrap();
+    assert_eq!(roundtrip, ctx);
+}
+
+#[test]
+fn tls_fields_omitted_when_none() {
+    let ctx =
+        resolve_server_override("https://example.com", "key".into(), "secret".into()).unwrap();
+    let yaml = serde_yaml::to_string(&ctx).unwrap();
+    assert!(
+        !yaml.contains("certificate") && !yaml.contains("client-key"),
+        "TLS fields should be omitted when None, got: {yaml}"
+    );
+}
@@ -15,6 +15,10 @@ fn invalid_credentials_returns_error() {
         api_secret: "bogus-api-secret".to_string(),
         insecure_skip_tls_verify: ctx.insecure_skip_tls_verify,
         timeout_seconds: None,
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
     };
     let client = KasmClient::new(&bad_ctx).unwrap();
 
@@ -35,6 +39,10 @@ fn invalid_server_url_returns_connection_error() {
         api_secret: "irrelevant".to_string(),
         insecure_skip_tls_verify: false,
         timeout_seconds: None,
+        retry: None,
+        certificate_authority: None,
+        client_certificate: None,
+        client_key: None,
     };
     let client = KasmClient::new(&bad_ctx).unwrap();
 
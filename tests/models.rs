@@ -1,8 +1,10 @@
 use kasmctl::models::agent::Agent;
+use kasmctl::models::group::Group;
 use kasmctl::models::image::Image;
 use kasmctl::models::report::{AgentResourceReport, ScalarReportData, format_bytes_human};
 use kasmctl::models::server::Server;
 use kasmctl::models::session::{CreateSessionResponse, Session, SessionImage};
+use kasmctl::models::user::{User, UserGroup};
 use kasmctl::models::zone::Zone;
 use kasmctl::output::display::short_id;
 use kasmctl::resource::Resource;
@@ -61,6 +63,7 @@ fn arb_image() -> impl Strategy<Value = Image> {
                     cores,
                     memory,
                     image_src,
+                    ..Default::default()
                 }
             },
         )
@@ -554,6 +557,7 @@ fn image_table_detail_has_all_labels() {
         cores: None,
         memory: None,
         image_src: None,
+        ..Default::default()
     };
     let detail = image.table_detail();
     let labels: Vec<&str> = detail.iter().map(|(k, _)| *k).collect();
@@ -571,6 +575,7 @@ fn image_table_detail_contains_field_values() {
         cores: Some(2.0),
         memory: Some(2_147_483_648),
         image_src: Some("https://kasm.example.com/img.png".into()),
+        ..Default::default()
     };
     let detail = image.table_detail();
     let lookup = |label: &str| {
@@ -608,6 +613,7 @@ fn image_memory_format_bytes_gb() {
         cores: None,
         memory: Some(4_294_967_296), // 4GB
         image_src: None,
+        ..Default::default()
     };
     let row = image.table_row();
     assert_eq!(row[5], "4GB");
@@ -624,6 +630,7 @@ fn image_memory_format_bytes_mb() {
         cores: None,
         memory: Some(524_288_000), // 500MB
         image_src: None,
+        ..Default::default()
     };
     let row = image.table_row();
     assert_eq!(row[5], "500MB");
@@ -640,6 +647,7 @@ fn image_memory_format_bytes_raw() {
         cores: None,
         memory: Some(12345),
         image_src: None,
+        ..Default::default()
     };
     let row = image.table_row();
     assert_eq!(row[5], "12345");
@@ -1409,3 +1417,319 @@ fn format_bytes_human_kb() {
     // 2 * 1024 = 2KB
     assert_eq!(format_bytes_human(2048), "2.0KB");
 }
+
+// ===================== User =====================
+
+fn arb_option_user_groups() -> impl Strategy<Value = Option<Vec<UserGroup>>> {
+    prop_oneof![
+        Just(None),
+        prop::collection::vec(
+            (arb_option_string(), arb_option_string())
+                .prop_map(|(group_id, name)| UserGroup { group_id, name }),
+            0..4,
+        )
+        .prop_map(Some),
+    ]
+}
+
+fn arb_user() -> impl Strategy<Value = User> {
+    (
+        (
+            "[a-zA-Z0-9-]{1,36}",
+            arb_option_string(),
+            arb_option_string(),
+            arb_option_string(),
+            arb_option_string(),
+            arb_option_string(),
+        ),
+        (
+            arb_option_string(),
+            arb_option_bool(),
+            arb_option_bool(),
+            arb_option_string(),
+            arb_option_string(),
+            arb_option_user_groups(),
+        ),
+    )
+        .prop_map(
+            |(
+                (user_id, username, first_name, last_name, phone, organization),
+                (realm, locked, disabled, created, last_session, groups),
+            )| User {
+                user_id,
+                username,
+                first_name,
+                last_name,
+                phone,
+                organization,
+                realm,
+                locked,
+                disabled,
+                created,
+                last_session,
+                groups,
+            },
+        )
+}
+
+proptest! {
+    #[test]
+    fn user_serde_roundtrip(user in arb_user()) {
+        let json = serde_json::to_string(&user).unwrap();
+        let deserialized: User = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(user, deserialized);
+    }
+
+    #[test]
+    fn user_table_row_length_matches_headers(user in arb_user()) {
+        let headers = User::table_headers();
+        let row = user.table_row();
+        prop_assert_eq!(row.len(), headers.len());
+    }
+
+    #[test]
+    fn user_table_row_first_column_is_user_id(user in arb_user()) {
+        let row = user.table_row();
+        prop_assert_eq!(&row[0], short_id(&user.user_id));
+    }
+
+    #[test]
+    fn user_table_detail_contains_user_id(user in arb_user()) {
+        let detail = user.table_detail();
+        let entry = detail.iter().find(|(k, _)| *k == "USER ID");
+        prop_assert!(entry.is_some());
+        prop_assert_eq!(&entry.unwrap().1, &user.user_id);
+    }
+}
+
+#[test]
+fn user_resource_name_is_user() {
+    assert_eq!(User::resource_name(), "User");
+}
+
+#[test]
+fn user_table_headers_are_correct() {
+    assert_eq!(
+        User::table_headers(),
+        vec![
+            "USER ID",
+            "USERNAME",
+            "NAME",
+            "REALM",
+            "LOCKED",
+            "DISABLED",
+            "LAST SESSION"
+        ]
+    );
+}
+
+#[test]
+fn user_table_detail_has_all_labels() {
+    let expected_labels = vec![
+        "USER ID",
+        "USERNAME",
+        "FIRST NAME",
+        "LAST NAME",
+        "PHONE",
+        "ORGANIZATION",
+        "REALM",
+        "LOCKED",
+        "DISABLED",
+        "GROUPS",
+        "CREATED",
+        "LAST SESSION",
+    ];
+    let user = User {
+        user_id: "test-id".into(),
+        ..Default::default()
+    };
+    let detail = user.table_detail();
+    let labels: Vec<&str> = detail.iter().map(|(k, _)| *k).collect();
+    assert_eq!(labels, expected_labels);
+}
+
+#[test]
+fn user_table_row_joins_first_and_last_name() {
+    let user = User {
+        user_id: "user-001".into(),
+        first_name: Some("Ada".into()),
+        last_name: Some("Lovelace".into()),
+        ..Default::default()
+    };
+    assert_eq!(user.table_row()[2], "Ada Lovelace");
+
+    let first_only = User {
+        user_id: "user-002".into(),
+        first_name: Some("Ada".into()),
+        ..Default::default()
+    };
+    assert_eq!(first_only.table_row()[2], "Ada");
+}
+
+#[test]
+fn user_table_detail_lists_group_names() {
+    let user = User {
+        user_id: "user-001".into(),
+        groups: Some(vec![
+            UserGroup {
+                group_id: Some("grp-1".into()),
+                name: Some("All Users".into()),
+            },
+            UserGroup {
+                group_id: Some("grp-2".into()),
+                name: None,
+            },
+        ]),
+        ..Default::default()
+    };
+    let detail = user.table_detail();
+    let groups = detail.iter().find(|(k, _)| *k == "GROUPS").unwrap();
+    assert_eq!(groups.1, "All Users, grp-2");
+}
+
+#[test]
+fn deserialize_missing_required_user_id_fails() {
+    let json = r#"{"username": "alice@example.com"}"#;
+    let result = serde_json::from_str::<User>(json);
+    assert!(result.is_err());
+}
+
+// ===================== Group =====================
+
+fn arb_group() -> impl Strategy<Value = Group> {
+    (
+        "[a-zA-Z0-9-]{1,36}",
+        arb_option_string(),
+        arb_option_string(),
+        prop_oneof![Just(None), any::<i32>().prop_map(Some)],
+        arb_option_bool(),
+    )
+        .prop_map(|(group_id, name, description, priority, is_system)| Group {
+            group_id,
+            name,
+            description,
+            priority,
+            is_system,
+        })
+}
+
+proptest! {
+    #[test]
+    fn group_serde_roundtrip(group in arb_group()) {
+        let json = serde_json::to_string(&group).unwrap();
+        let deserialized: Group = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(group, deserialized);
+    }
+
+    #[test]
+    fn group_table_row_length_matches_headers(group in arb_group()) {
+        let headers = Group::table_headers();
+        let row = group.table_row();
+        prop_assert_eq!(row.len(), headers.len());
+    }
+
+    #[test]
+    fn group_table_row_first_column_is_group_id(group in arb_group()) {
+        let row = group.table_row();
+        prop_assert_eq!(&row[0], short_id(&group.group_id));
+    }
+}
+
+#[test]
+fn group_resource_name_is_group() {
+    assert_eq!(Group::resource_name(), "Group");
+}
+
+#[test]
+fn group_table_headers_are_correct() {
+    assert_eq!(
+        Group::table_headers(),
+        vec!["GROUP ID", "NAME", "PRIORITY", "SYSTEM", "DESCRIPTION"]
+    );
+}
+
+#[test]
+fn group_table_detail_has_all_labels() {
+    let group = Group {
+        group_id: "test-id".into(),
+        ..Default::default()
+    };
+    let detail = group.table_detail();
+    let labels: Vec<&str> = detail.iter().map(|(k, _)| *k).collect();
+    assert_eq!(
+        labels,
+        vec!["GROUP ID", "NAME", "DESCRIPTION", "PRIORITY", "SYSTEM"]
+    );
+}
+
+// ===================== Wide output =====================
+
+proptest! {
+    #[test]
+    fn session_wide_row_length_matches_headers(session in arb_session()) {
+        prop_assert_eq!(session.wide_row().len(), Session::wide_headers().len());
+        prop_assert_eq!(&session.wide_row()[..5], &session.table_row()[..]);
+    }
+
+    #[test]
+    fn image_wide_row_length_matches_headers(image in arb_image()) {
+        prop_assert_eq!(image.wide_row().len(), Image::wide_headers().len());
+    }
+
+    #[test]
+    fn zone_wide_row_length_matches_headers(zone in arb_zone()) {
+        prop_assert_eq!(zone.wide_row().len(), Zone::wide_headers().len());
+    }
+
+    #[test]
+    fn agent_wide_row_length_matches_headers(agent in arb_agent()) {
+        prop_assert_eq!(agent.wide_row().len(), Agent::wide_headers().len());
+    }
+
+    #[test]
+    fn server_wide_row_length_matches_headers(server in arb_server()) {
+        prop_assert_eq!(server.wide_row().len(), Server::wide_headers().len());
+    }
+
+    #[test]
+    fn agent_report_wide_row_length_matches_headers(report in arb_agent_resource_report()) {
+        prop_assert_eq!(report.wide_row().len(), AgentResourceReport::wide_headers().len());
+    }
+}
+
+#[test]
+fn session_wide_headers_extend_table_headers() {
+    assert_eq!(
+        Session::wide_headers(),
+        vec![
+            "KASM ID", "STATUS", "IMAGE", "USER", "AGE", "HOST", "SERVER", "IDLE", "EXPIRES"
+        ]
+    );
+}
+
+#[test]
+fn session_wide_row_shows_host_server_and_expiry() {
+    let session = Session {
+        kasm_id: "abc-123".into(),
+        hostname: Some("agent-1.example.com".into()),
+        server_id: Some("0123456789abcdef".into()),
+        keepalive_date: Some("not-a-date".into()),
+        expiration_date: Some("2999-01-01 00:00:00".into()),
+        ..Default::default()
+    };
+    let row = session.wide_row();
+    assert_eq!(row[5], "agent-1.example.com");
+    assert_eq!(row[6], "01234567");
+    assert_eq!(row[7], "not-a-date");
+    assert!(row[8].starts_with("in "), "expiry was: {}", row[8]);
+}
+
+#[test]
+fn wide_defaults_to_table_columns() {
+    let group = Group {
+        group_id: "g-1".into(),
+        ..Default::default()
+    };
+    assert_eq!(Group::wide_headers(), Group::table_headers());
+    assert_eq!(group.wide_row(), group.table_row());
+}
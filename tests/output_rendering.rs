@@ -49,6 +49,7 @@ fn arb_image() -> impl Strategy<Value = Image> {
                     cores,
                     memory,
                     image_src,
+                    ..Default::default()
                 }
             },
         )
@@ -432,6 +433,7 @@ fn image_table_list_includes_footer_count() {
         cores: None,
         memory: None,
         image_src: None,
+        ..Default::default()
     };
     let output = output::render_list(&[image], &OutputFormat::Table).unwrap();
     assert!(output.ends_with("\n1 image"), "output was: {output}");
@@ -742,3 +744,175 @@ fn server_table_list_includes_footer_count() {
     let output = output::render_list(&[server], &OutputFormat::Table).unwrap();
     assert!(output.ends_with("\n1 server"), "output was: {output}");
 }
+
+// ===================== Name, JSONPath and custom-columns output =====================
+
+fn parse_format(s: &str) -> OutputFormat {
+    s.parse().unwrap()
+}
+
+fn scripting_sessions() -> Vec<Session> {
+    vec![
+        Session {
+            kasm_id: "kasm-0001-full-id".into(),
+            hostname: Some("agent-1".into()),
+            share_id: Some("share-001".into()),
+            container_id: Some("c0ffee".into()),
+            image: Some(SessionImage {
+                friendly_name: Some("Terminal".into()),
+                name: None,
+            }),
+            ..Default::default()
+        },
+        Session {
+            kasm_id: "kasm-0002-full-id".into(),
+            hostname: Some("agent-2".into()),
+            ..Default::default()
+        },
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(50))]
+
+    #[test]
+    fn name_render_list_has_one_full_id_per_line(sessions in arb_sessions()) {
+        let output = output::render_list(&sessions, &OutputFormat::Name).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        prop_assert_eq!(lines.len(), sessions.len());
+        for (line, session) in lines.iter().zip(&sessions) {
+            prop_assert_eq!(*line, format!("session/{}", session.kasm_id));
+        }
+    }
+}
+
+#[test]
+fn name_render_one_uses_kind_and_full_id() {
+    let image = Image {
+        image_id: "img-0123456789".into(),
+        ..Default::default()
+    };
+    let output = output::render_one(&image, &OutputFormat::Name).unwrap();
+    assert_eq!(output, "image/img-0123456789");
+}
+
+#[test]
+fn jsonpath_render_list_evaluates_per_item() {
+    let output =
+        output::render_list(&scripting_sessions(), &parse_format("jsonpath={.kasm_id}")).unwrap();
+    assert_eq!(output, "kasm-0001-full-id\nkasm-0002-full-id");
+}
+
+#[test]
+fn jsonpath_reaches_fields_not_in_table_headers() {
+    let sessions = scripting_sessions();
+    let format = parse_format("jsonpath={.container_id} {.image.friendly_name}");
+    let output = output::render_one(&sessions[0], &format).unwrap();
+    assert_eq!(output, "c0ffee Terminal");
+}
+
+#[test]
+fn custom_columns_render_list_with_missing_values() {
+    let format = parse_format("custom-columns=ID:.kasm_id,HOST:.hostname,SHARE:.share_id");
+    let output = output::render_list(&scripting_sessions(), &format).unwrap();
+    assert_eq!(
+        output,
+        "ID                  HOST      SHARE\n\
+         kasm-0001-full-id   agent-1   share-001\n\
+         kasm-0002-full-id   agent-2   <none>"
+    );
+}
+
+#[test]
+fn custom_columns_render_list_empty_prints_header() {
+    let format = parse_format("custom-columns=ID:.kasm_id");
+    let output = output::render_list::<Session>(&[], &format).unwrap();
+    assert_eq!(output, "ID");
+}
+
+// ===================== Wide output =====================
+
+#[test]
+fn wide_render_list_adds_extra_columns() {
+    let sessions = scripting_sessions();
+    let table = output::render_list(&sessions, &OutputFormat::Table).unwrap();
+    let wide = output::render_list(&sessions, &OutputFormat::Wide).unwrap();
+    assert!(!table.contains("HOST"));
+    assert!(wide.contains("HOST"));
+    assert!(wide.contains("EXPIRES"));
+    assert!(wide.contains("agent-1"));
+    assert!(wide.ends_with("\n2 sessions"), "output was: {wide}");
+}
+
+#[test]
+fn wide_render_one_matches_table_detail() {
+    let session = &scripting_sessions()[0];
+    assert_eq!(
+        output::render_one(session, &OutputFormat::Wide).unwrap(),
+        output::render_one(session, &OutputFormat::Table).unwrap()
+    );
+}
+
+#[test]
+fn wide_render_list_empty_matches_table() {
+    let output = output::render_list::<Server>(&[], &OutputFormat::Wide).unwrap();
+    assert_eq!(output, "No servers found.");
+}
+
+// ===================== CSV and TSV output =====================
+
+#[test]
+fn csv_render_list_uses_table_columns() {
+    let sessions = scripting_sessions();
+    let output = output::render_list(&sessions, &"csv".parse().unwrap()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "KASM ID,STATUS,IMAGE,USER,AGE");
+    assert_eq!(lines[1], "kasm-000,,Terminal,,");
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn csv_render_list_quotes_commas_and_newlines() {
+    let image = Image {
+        image_id: "img-1".into(),
+        friendly_name: Some("Desktop, \"full\"\nedition".into()),
+        ..Default::default()
+    };
+    let output = output::render_list(&[image], &"csv".parse().unwrap()).unwrap();
+    assert!(
+        output.contains("img-1,\"Desktop, \"\"full\"\"\nedition\","),
+        "output was: {output}"
+    );
+}
+
+#[test]
+fn tsv_render_list_uses_tabs() {
+    let zone = Zone {
+        zone_id: "zone-1".into(),
+        zone_name: Some("Default".into()),
+        ..Default::default()
+    };
+    let output = output::render_list(&[zone], &"tsv".parse().unwrap()).unwrap();
+    assert_eq!(
+        output,
+        "ZONE ID\tNAME\tLOAD BALANCING\tPROXY\nzone-1\tDefault\t\t"
+    );
+}
+
+#[test]
+fn csv_all_fields_includes_every_serialized_field() {
+    let sessions = scripting_sessions();
+    let format = OutputFormat::Csv { all_fields: true };
+    let output = output::render_list(&sessions, &format).unwrap();
+    let header = output.lines().next().unwrap();
+    assert!(header.starts_with("kasm_id,user_id,image_id,image.friendly_name,image.name,"));
+    assert!(header.contains("container_id"));
+    assert!(output.contains("kasm-0001-full-id,"));
+    assert!(output.contains("c0ffee"));
+}
+
+#[test]
+fn csv_empty_list_prints_header_only() {
+    let output = output::render_list::<Session>(&[], &"csv".parse().unwrap()).unwrap();
+    assert_eq!(output, "KASM ID,STATUS,IMAGE,USER,AGE");
+}